@@ -0,0 +1,8 @@
+pub mod stats;
+pub mod timeline;
+pub mod build;
+pub mod objectives;
+pub mod heatmap;
+pub mod profile;
+pub mod duo;
+pub mod matchup;
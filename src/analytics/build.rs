@@ -0,0 +1,279 @@
+use crate::ddragon::StaticData;
+use crate::endpoints::constants::{LevelUpType, MatchTimelineDto, MatchTimelineInfoFrameEvent};
+use std::{fmt, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemChange {
+    Purchased(i32),
+    Sold(i32),
+    Destroyed(i32),
+    /// Undoing a purchase has `before_id` set to the item and `after_id` 0, undoing a sale
+    /// the other way round.
+    Undo { before_id: i32, after_id: i32 }
+}
+
+/// The item events of one participant, replayed to reconstruct their inventory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemTimeline {
+    pub participant_id: i32,
+    pub changes: Vec<(Duration, ItemChange)>
+}
+
+#[derive(Debug, Default)]
+struct Replay {
+    inventory: Vec<i32>,
+    purchases: Vec<(Duration, i32)>,
+    destroyed: Vec<(Duration, i32)>
+}
+
+impl Replay {
+    fn remove(&mut self, item_id: i32) {
+        if let Some(index) = self.inventory.iter().position(|id| *id == item_id) {
+            self.inventory.remove(index);
+        }
+    }
+
+    fn apply(&mut self, time: Duration, change: ItemChange) {
+        match change {
+            ItemChange::Purchased(item_id) => {
+                self.inventory.push(item_id);
+                self.purchases.push((time, item_id));
+            },
+            ItemChange::Sold(item_id) => self.remove(item_id),
+            ItemChange::Destroyed(item_id) => {
+                self.remove(item_id);
+                self.destroyed.push((time, item_id));
+            },
+            ItemChange::Undo { before_id, after_id } => {
+                if before_id != 0 {
+                    self.remove(before_id);
+                    self.undo_purchase(before_id);
+                }
+                if after_id != 0 {
+                    self.inventory.push(after_id);
+                }
+            }
+        }
+    }
+
+    /// Forgets the latest purchase of `item_id` and gives back the components that were
+    /// destroyed when it was bought.
+    fn undo_purchase(&mut self, item_id: i32) {
+        let Some(index) = self.purchases.iter().rposition(|(_, id)| *id == item_id) else { return };
+        let (purchased_at, _) = self.purchases.remove(index);
+
+        let (components, destroyed) = self
+            .destroyed
+            .drain(..)
+            .partition(|(time, _)| *time == purchased_at);
+        self.destroyed = destroyed;
+        self.inventory.extend(components.into_iter().map(|(_, id): (Duration, i32)| id));
+    }
+}
+
+impl ItemTimeline {
+    pub fn new(timeline: &MatchTimelineDto, participant_id: i32) -> Self {
+        let changes = timeline
+            .info
+            .frames
+            .iter()
+            .flat_map(|frame| &frame.events)
+            .filter_map(|event| {
+                let change = match *event {
+                    MatchTimelineInfoFrameEvent::ItemPurchased { participant_id: id, item_id, .. } if id == participant_id => ItemChange::Purchased(item_id),
+                    MatchTimelineInfoFrameEvent::ItemSold { participant_id: id, item_id, .. } if id == participant_id => ItemChange::Sold(item_id),
+                    MatchTimelineInfoFrameEvent::ItemDestroyed { participant_id: id, item_id, .. } if id == participant_id => ItemChange::Destroyed(item_id),
+                    MatchTimelineInfoFrameEvent::ItemUndo { participant_id: id, before_id, after_id, .. } if id == participant_id => ItemChange::Undo { before_id, after_id },
+                    _ => return None
+                };
+                Some((event.time(), change))
+            })
+            .collect();
+
+        ItemTimeline { participant_id, changes }
+    }
+
+    fn replay(&self, until: Duration) -> Replay {
+        let mut replay = Replay::default();
+        for (time, change) in self.changes.iter().take_while(|(time, _)| *time <= until) {
+            replay.apply(*time, *change);
+        }
+        replay
+    }
+
+    /// Item ids held at `time`, in the order they were acquired.
+    pub fn inventory_at(&self, time: Duration) -> Vec<i32> {
+        self.replay(time).inventory
+    }
+
+    /// Every purchase that was not undone, in order.
+    pub fn purchases(&self) -> Vec<(Duration, i32)> {
+        self.replay(Duration::MAX).purchases
+    }
+
+    /// Purchases of finished items, i.e. items built from components that do not build into
+    /// anything else, according to `data`.
+    pub fn completed_items(&self, data: &StaticData) -> Vec<(Duration, i32)> {
+        self.purchases()
+            .into_iter()
+            .filter(|(_, item_id)| data.item(*item_id).is_some_and(|item| !item.from.is_empty() && item.into.is_empty()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Skill {
+    Q,
+    W,
+    E,
+    R
+}
+
+impl Skill {
+    pub fn from_slot(skill_slot: i32) -> Option<Self> {
+        match skill_slot {
+            1 => Some(Skill::Q),
+            2 => Some(Skill::W),
+            3 => Some(Skill::E),
+            4 => Some(Skill::R),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Skill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let skill = match self {
+            Skill::Q => "Q",
+            Skill::W => "W",
+            Skill::E => "E",
+            Skill::R => "R"
+        };
+        f.write_str(skill)
+    }
+}
+
+/// The skill level-ups of one participant. Evolutions, like Kha'Zix's, are not counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillOrder {
+    pub participant_id: i32,
+    pub level_ups: Vec<(Duration, Skill)>
+}
+
+impl SkillOrder {
+    pub fn new(timeline: &MatchTimelineDto, participant_id: i32) -> Self {
+        let level_ups = timeline
+            .info
+            .frames
+            .iter()
+            .flat_map(|frame| &frame.events)
+            .filter_map(|event| match event {
+                MatchTimelineInfoFrameEvent::SkillLevelUp { participant_id: id, skill_slot, level_up_type: LevelUpType::Normal, .. } if *id == participant_id => {
+                    Some((event.time(), Skill::from_slot(*skill_slot)?))
+                },
+                _ => None
+            })
+            .collect();
+
+        SkillOrder { participant_id, level_ups }
+    }
+
+    pub fn sequence(&self) -> Vec<Skill> {
+        self.level_ups.iter().map(|(_, skill)| *skill).collect()
+    }
+
+    /// Order the basic abilities were maxed in, e.g. "Q>E>W". Abilities with more points come
+    /// first, and of those with equally many the one that got its last point earlier, so games
+    /// that end before anything is maxed still get a sensible order. Unlearned abilities are
+    /// left out.
+    pub fn max_order(&self) -> String {
+        let mut skills: Vec<(Skill, usize, usize)> = [Skill::Q, Skill::W, Skill::E]
+            .into_iter()
+            .filter_map(|skill| {
+                let points = self.level_ups.iter().filter(|(_, s)| *s == skill).count();
+                let last = self.level_ups.iter().rposition(|(_, s)| *s == skill)?;
+                Some((skill, points, last))
+            })
+            .collect();
+        skills.sort_by_key(|&(_, points, last)| (std::cmp::Reverse(points), last));
+
+        skills
+            .iter()
+            .map(|(skill, ..)| skill.to_string())
+            .collect::<Vec<_>>()
+            .join(">")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn undone_purchase_is_not_in_inventory() {
+        let items = ItemTimeline::new(&fixtures::timeline(), 3);
+
+        // Boots bought at 0:09 and undone half a second later
+        assert_eq!(items.inventory_at(Duration::from_millis(9000)), [1056, 2003, 2003, 3340, 1001]);
+        assert_eq!(items.inventory_at(secs(10)), [1056, 2003, 2003, 3340]);
+        assert_eq!(items.purchases().iter().filter(|(_, id)| *id == 1001).count(), 1);
+        assert!(!items.inventory_at(secs(1300)).contains(&1056));
+    }
+
+    #[test]
+    fn undo_restores_components_and_sales() {
+        let items = ItemTimeline {
+            participant_id: 1,
+            changes: vec![
+                (secs(1), ItemChange::Purchased(1036)),
+                (secs(2), ItemChange::Purchased(1036)),
+                (secs(300), ItemChange::Destroyed(1036)),
+                (secs(300), ItemChange::Purchased(3133)),
+                (secs(301), ItemChange::Undo { before_id: 3133, after_id: 0 }),
+                (secs(302), ItemChange::Sold(1036)),
+                (secs(303), ItemChange::Undo { before_id: 0, after_id: 1036 })
+            ]
+        };
+
+        assert_eq!(items.inventory_at(secs(300)), [1036, 3133]);
+        assert_eq!(items.inventory_at(secs(301)), [1036, 1036]);
+        assert_eq!(items.inventory_at(secs(302)), [1036]);
+        assert_eq!(items.inventory_at(secs(303)), [1036, 1036]);
+        assert_eq!(items.purchases(), [(secs(1), 1036), (secs(2), 1036)]);
+    }
+
+    #[test]
+    fn completed_item_order() {
+        let data = StaticData::new(
+            "12.21.1",
+            "en_US",
+            serde_json::from_str(include_str!("../../tests/fixtures/ddragon/cdn/12.21.1/data/en_US/champion.json")).expect("Failed to deserialize"),
+            serde_json::from_str(include_str!("../../tests/fixtures/ddragon/cdn/12.21.1/data/en_US/item.json")).expect("Failed to deserialize"),
+            serde_json::from_str(include_str!("../../tests/fixtures/ddragon/cdn/12.21.1/data/en_US/summoner.json")).expect("Failed to deserialize"),
+            serde_json::from_str(include_str!("../../tests/fixtures/ddragon/cdn/12.21.1/data/en_US/runesReforged.json")).expect("Failed to deserialize")
+        );
+        let items = ItemTimeline::new(&fixtures::timeline(), 3);
+
+        let completed: Vec<_> = items.completed_items(&data).into_iter().map(|(_, id)| id).collect();
+        assert_eq!(completed, [3152, 3020, 4645]);
+    }
+
+    #[test]
+    fn skill_max_order() {
+        let skills = SkillOrder::new(&fixtures::timeline(), 3);
+
+        assert_eq!(skills.sequence().len(), 18);
+        assert_eq!(skills.sequence()[..3], [Skill::Q, Skill::E, Skill::W]);
+        assert_eq!(skills.max_order(), "Q>E>W");
+
+        let early = SkillOrder { participant_id: 3, level_ups: skills.level_ups[..4].to_vec() };
+        assert_eq!(early.max_order(), "Q>E>W");
+        let early = SkillOrder { participant_id: 3, level_ups: skills.level_ups[..1].to_vec() };
+        assert_eq!(early.max_order(), "Q");
+    }
+}
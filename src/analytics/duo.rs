@@ -0,0 +1,146 @@
+use super::stats::{is_remake, ratio};
+use crate::endpoints::constants::MatchDto;
+use std::collections::{HashMap, HashSet};
+
+/// A teammate of the tracked player, with the player's results with and without them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Duo {
+    pub puuid: String,
+    pub games_together: u32,
+    pub wins_together: u32,
+    pub games_apart: u32,
+    pub wins_apart: u32
+}
+
+impl Duo {
+    pub fn win_rate_together(&self) -> f64 {
+        ratio(f64::from(self.wins_together), f64::from(self.games_together))
+    }
+
+    pub fn win_rate_apart(&self) -> f64 {
+        ratio(f64::from(self.wins_apart), f64::from(self.games_apart))
+    }
+}
+
+/// Finds players who regularly end up on the same team as a player, which usually means they
+/// queued together. Like [`PlayerProfile`](super::profile::PlayerProfile), matches are added
+/// incrementally and remakes, matches without the player and duplicates are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuoFinder {
+    pub puuid: String,
+    min_games: u32,
+    games: u32,
+    wins: u32,
+    /// Games and wins together per teammate.
+    teammates: HashMap<String, (u32, u32)>,
+    match_ids: HashSet<String>
+}
+
+impl DuoFinder {
+    pub const DEFAULT_MIN_GAMES: u32 = 3;
+
+    pub fn new(puuid: impl Into<String>) -> Self {
+        DuoFinder {
+            puuid: puuid.into(),
+            min_games: Self::DEFAULT_MIN_GAMES,
+            games: 0,
+            wins: 0,
+            teammates: HashMap::new(),
+            match_ids: HashSet::new()
+        }
+    }
+
+    /// Minimum number of games together for a teammate to count as a duo.
+    pub fn with_min_games(mut self, min_games: u32) -> Self {
+        self.min_games = min_games;
+        self
+    }
+
+    /// Adds a match, returning whether it was counted.
+    pub fn add(&mut self, match_: &MatchDto) -> bool {
+        if self.match_ids.contains(&match_.metadata.match_id) || is_remake(&match_.info) {
+            return false;
+        }
+        let participant_by_puuid = |puuid: &str| {
+            match_
+                .info
+                .participants
+                .iter()
+                .find(|participant| participant.puuid == puuid)
+        };
+        let Some(player) = participant_by_puuid(&self.puuid) else { return false };
+
+        self.match_ids.insert(match_.metadata.match_id.clone());
+        self.games += 1;
+        self.wins += u32::from(player.win);
+
+        for puuid in &match_.metadata.participants {
+            if *puuid == self.puuid || participant_by_puuid(puuid).map(|participant| participant.team_id) != Some(player.team_id) {
+                continue;
+            }
+            let (games, wins) = self.teammates.entry(puuid.clone()).or_default();
+            *games += 1;
+            *wins += u32::from(player.win);
+        }
+        true
+    }
+
+    pub fn games(&self) -> u32 {
+        self.games
+    }
+
+    /// Teammates with at least the minimum number of games together, most games first.
+    pub fn duos(&self) -> Vec<Duo> {
+        let mut duos: Vec<_> = self
+            .teammates
+            .iter()
+            .filter(|(_, (games, _))| *games >= self.min_games)
+            .map(|(puuid, (games, wins))| Duo {
+                puuid: puuid.clone(),
+                games_together: *games,
+                wins_together: *wins,
+                games_apart: self.games - games,
+                wins_apart: self.wins - wins
+            })
+            .collect();
+        duos.sort_by(|a, b| b.games_together.cmp(&a.games_together).then_with(|| a.puuid.cmp(&b.puuid)));
+        duos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn duos_across_matches() {
+        let first = fixtures::match_();
+        let puuid = first.info.participants[2].puuid.clone();
+        let duo = first.info.participants[3].puuid.clone();
+
+        // Same lobby again, but the duo on the other team and a loss.
+        let mut second = fixtures::match_();
+        second.metadata.match_id = "EUW1_6151255545".to_string();
+        second.info.participants[3].team_id = 200;
+        for participant in &mut second.info.participants {
+            participant.win = !participant.win;
+        }
+
+        let mut finder = DuoFinder::new(&puuid).with_min_games(1);
+        assert!(finder.add(&first));
+        assert!(finder.add(&second));
+        assert!(!finder.add(&second));
+        assert_eq!(finder.games(), 2);
+
+        let duos = finder.duos();
+        assert_eq!(duos.len(), 4);
+        let together = duos.iter().find(|candidate| candidate.puuid == duo).expect("Duo missing");
+        assert_eq!((together.games_together, together.wins_together, together.games_apart, together.wins_apart), (1, 1, 1, 0));
+        assert_eq!((together.win_rate_together(), together.win_rate_apart()), (1.0, 0.0));
+        assert_eq!(duos[0].games_together, 2);
+        assert_eq!(duos[0].win_rate_together(), 0.5);
+
+        assert_eq!(finder.with_min_games(2).duos().len(), 3);
+    }
+}
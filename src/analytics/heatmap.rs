@@ -0,0 +1,215 @@
+use crate::catalog::Map;
+use crate::endpoints::constants::{MatchDto, MatchTimelinPosition, MatchTimelineDto, MatchTimelineInfoFrameEvent};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The playable area of a map in game coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapBounds {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32
+}
+
+impl MapBounds {
+    pub const SUMMONERS_RIFT: MapBounds = MapBounds { min_x: -120, min_y: -120, max_x: 14870, max_y: 14980 };
+    pub const HOWLING_ABYSS: MapBounds = MapBounds { min_x: -28, min_y: -19, max_x: 12849, max_y: 12858 };
+
+    pub fn for_map(map: Map) -> Option<Self> {
+        match map {
+            Map::SummonersRift => Some(Self::SUMMONERS_RIFT),
+            Map::HowlingAbyss => Some(Self::HOWLING_ABYSS),
+            _ => None
+        }
+    }
+
+    /// Position scaled to `0.0..=1.0` on both axes, with `(0.0, 0.0)` in the blue side corner.
+    /// Positions outside the bounds are clamped.
+    pub fn normalize(&self, position: &MatchTimelinPosition) -> (f64, f64) {
+        let scale = |value: i32, min: i32, max: i32| (f64::from(value - min) / f64::from(max - min)).clamp(0.0, 1.0);
+        (scale(position.x, self.min_x, self.max_x), scale(position.y, self.min_y, self.max_y))
+    }
+}
+
+/// Counts of positions binned into a square grid over the map. `cells[row][column]`, where row 0
+/// is the bottom of the map and column 0 its left side.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    pub size: usize,
+    pub cells: Vec<Vec<u32>>
+}
+
+impl Heatmap {
+    pub fn new(size: usize) -> Self {
+        Heatmap { size, cells: vec![vec![0; size]; size] }
+    }
+
+    pub fn add(&mut self, bounds: &MapBounds, position: &MatchTimelinPosition) {
+        let (x, y) = bounds.normalize(position);
+        let bin = |value: f64| ((value * self.size as f64) as usize).min(self.size.saturating_sub(1));
+        let (column, row) = (bin(x), bin(y));
+        if let Some(cell) = self.cells.get_mut(row).and_then(|cells| cells.get_mut(column)) {
+            *cell += 1;
+        }
+    }
+
+    pub fn get(&self, column: usize, row: usize) -> u32 {
+        self.cells.get(row).and_then(|cells| cells.get(column)).copied().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> u32 {
+        self.cells.iter().flatten().sum()
+    }
+}
+
+/// Participant ids of a team in `match_`.
+pub fn team_participant_ids(match_: &MatchDto, team_id: i32) -> Vec<i32> {
+    match_
+        .info
+        .participants
+        .iter()
+        .filter(|participant| participant.team_id == team_id)
+        .map(|participant| participant.participant_id)
+        .collect()
+}
+
+/// Positions of the given participants at every timeline frame.
+pub fn position_heatmap(timeline: &MatchTimelineDto, bounds: &MapBounds, size: usize, participant_ids: &[i32]) -> Heatmap {
+    let mut heatmap = Heatmap::new(size);
+    for frame in &timeline.info.frames {
+        for participant_id in participant_ids {
+            if let Some(participant_frame) = frame.participant_frames.get(*participant_id) {
+                heatmap.add(bounds, &participant_frame.position);
+            }
+        }
+    }
+    heatmap
+}
+
+/// Where the given participants died.
+pub fn death_heatmap(timeline: &MatchTimelineDto, bounds: &MapBounds, size: usize, participant_ids: &[i32]) -> Heatmap {
+    let mut heatmap = Heatmap::new(size);
+    for event in timeline.info.frames.iter().flat_map(|frame| &frame.events) {
+        if let MatchTimelineInfoFrameEvent::ChampionKill { victim_id, position, .. } = event {
+            if participant_ids.contains(victim_id) {
+                heatmap.add(bounds, position);
+            }
+        }
+    }
+    heatmap
+}
+
+/// Where the given participants placed wards. Ward events carry no position, so the creator's
+/// position at the closest frame is used instead.
+pub fn ward_heatmap(timeline: &MatchTimelineDto, bounds: &MapBounds, size: usize, participant_ids: &[i32]) -> Heatmap {
+    let mut heatmap = Heatmap::new(size);
+    for event in timeline.info.frames.iter().flat_map(|frame| &frame.events) {
+        if let MatchTimelineInfoFrameEvent::WardPlaced { timestamp, creator_id, .. } = event {
+            if !participant_ids.contains(creator_id) {
+                continue;
+            }
+            let closest = timeline
+                .info
+                .frames
+                .iter()
+                .min_by_key(|frame| (frame.timestamp - timestamp).abs())
+                .and_then(|frame| frame.participant_frames.get(*creator_id));
+            if let Some(participant_frame) = closest {
+                heatmap.add(bounds, &participant_frame.position);
+            }
+        }
+    }
+    heatmap
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathPoint {
+    pub time: Duration,
+    pub position: MatchTimelinPosition
+}
+
+/// Known positions of a participant up to `until`, in order: their position at every frame, plus
+/// the kills and epic monsters they took part in. Typically used for a jungler's first minutes.
+pub fn jungle_path(timeline: &MatchTimelineDto, participant_id: i32, until: Duration) -> Vec<PathPoint> {
+    let mut path = Vec::new();
+    for frame in &timeline.info.frames {
+        if let Some(participant_frame) = frame.participant_frames.get(participant_id) {
+            path.push(PathPoint { time: frame.time(), position: participant_frame.position.clone() });
+        }
+
+        for event in &frame.events {
+            let position = match event {
+                MatchTimelineInfoFrameEvent::ChampionKill { killer_id, victim_id, assisting_participant_ids, position, .. }
+                    if *killer_id == participant_id
+                        || *victim_id == participant_id
+                        || assisting_participant_ids.contains(&participant_id) => position,
+                MatchTimelineInfoFrameEvent::EliteMonsterKill { killer_id, assisting_participant_ids, position, .. }
+                    if *killer_id == participant_id || assisting_participant_ids.contains(&participant_id) => position,
+                _ => continue
+            };
+            path.push(PathPoint { time: event.time(), position: position.clone() });
+        }
+    }
+
+    path.retain(|point| point.time <= until);
+    path.sort_by_key(|point| point.time);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn binning() {
+        let bounds = MapBounds::for_map(Map::SummonersRift).expect("No bounds");
+        let mut heatmap = Heatmap::new(4);
+        heatmap.add(&bounds, &MatchTimelinPosition { x: 554, y: 581 });
+        heatmap.add(&bounds, &MatchTimelinPosition { x: 14340, y: 14391 });
+        heatmap.add(&bounds, &MatchTimelinPosition { x: 20000, y: -500 });
+
+        assert_eq!(heatmap.get(0, 0), 1);
+        assert_eq!(heatmap.get(3, 3), 1);
+        assert_eq!(heatmap.get(3, 0), 1);
+        assert_eq!(heatmap.total(), 3);
+        assert_eq!(bounds.normalize(&MatchTimelinPosition { x: -120, y: 14980 }), (0.0, 1.0));
+        assert_eq!(MapBounds::for_map(Map::HowlingAbyss), Some(MapBounds::HOWLING_ABYSS));
+    }
+
+    #[test]
+    fn match_heatmaps() {
+        let (match_, timeline) = (fixtures::match_(), fixtures::timeline());
+        let bounds = MapBounds::for_map(match_.info.map_id).expect("No bounds");
+        let blue = team_participant_ids(&match_, 100);
+        let red = team_participant_ids(&match_, 200);
+        assert_eq!(blue, [1, 2, 3, 4, 5]);
+
+        let positions = position_heatmap(&timeline, &bounds, 10, &blue);
+        assert_eq!(positions.total() as usize, timeline.info.frames.len() * 5);
+        assert_eq!(death_heatmap(&timeline, &bounds, 10, &red).total(), 21);
+        assert_eq!(death_heatmap(&timeline, &bounds, 10, &blue).total(), 6);
+
+        let wards = timeline
+            .info
+            .frames
+            .iter()
+            .flat_map(|frame| &frame.events)
+            .filter(|event| matches!(event, MatchTimelineInfoFrameEvent::WardPlaced { creator_id: 2, .. }))
+            .count();
+        assert_eq!(ward_heatmap(&timeline, &bounds, 10, &[2]).total() as usize, wards);
+
+        let json = serde_json::to_value(&positions).expect("Failed to serialize");
+        assert_eq!(json["cells"].as_array().map(Vec::len), Some(10));
+    }
+
+    #[test]
+    fn early_jungle_path() {
+        let timeline = fixtures::timeline();
+        let path = jungle_path(&timeline, 2, Duration::from_secs(240));
+        assert!(path.len() >= 5);
+        assert!(path.windows(2).all(|points| points[0].time <= points[1].time));
+        assert!(path.iter().all(|point| point.time <= Duration::from_secs(240)));
+    }
+}
@@ -0,0 +1,320 @@
+use super::stats::{is_remake, ratio};
+use crate::catalog::Queue;
+use crate::champion::Champion;
+use crate::endpoints::constants::{MatchDto, Position};
+use crate::version::Patch;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::ops::{Bound, RangeBounds};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WinCount {
+    pub games: u32,
+    pub wins: u32
+}
+
+impl WinCount {
+    fn add(&mut self, win: bool) {
+        self.games += 1;
+        self.wins += u32::from(win);
+    }
+
+    /// From 0 to 1, or 0 without games.
+    pub fn win_rate(&self) -> f64 {
+        ratio(f64::from(self.wins), f64::from(self.games))
+    }
+}
+
+string_enum! {
+    /// Ranked tier of the players in a match, for [`MatchupAggregator::with_tiers`].
+    pub enum Tier {
+        Iron => "IRON",
+        Bronze => "BRONZE",
+        Silver => "SILVER",
+        Gold => "GOLD",
+        Platinum => "PLATINUM",
+        Emerald => "EMERALD",
+        Diamond => "DIAMOND",
+        Master => "MASTER",
+        Grandmaster => "GRANDMASTER",
+        Challenger => "CHALLENGER"
+    }
+}
+
+/// Serializes maps as lists of entries, since JSON only allows string keys.
+mod entries {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::{collections::HashMap, hash::Hash};
+
+    pub fn serialize<K: Serialize, V: Serialize, S: Serializer>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(map)
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>
+    {
+        Vec::<(K, V)>::deserialize(deserializer).map(|entries| entries.into_iter().collect())
+    }
+}
+
+/// The matches a [`MatchupAggregator`] counts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct Filters {
+    queues: Vec<Queue>,
+    patches: (Bound<Patch>, Bound<Patch>),
+    tiers: Vec<Tier>
+}
+
+impl Default for Filters {
+    fn default() -> Self {
+        Filters {
+            queues: Vec::new(),
+            patches: (Bound::Unbounded, Bound::Unbounded),
+            tiers: Vec::new()
+        }
+    }
+}
+
+impl Filters {
+    fn accepts(&self, match_: &MatchDto, tier: Option<&Tier>) -> bool {
+        let info = &match_.info;
+        (self.queues.is_empty() || self.queues.contains(&info.queue_id))
+            && (self.patches == (Bound::Unbounded, Bound::Unbounded) || info.patch().is_ok_and(|patch| self.patches.contains(&patch)))
+            && (self.tiers.is_empty() || tier.is_some_and(|tier| self.tiers.contains(tier)))
+    }
+}
+
+/// Counts gathered by a [`MatchupAggregator`], along with the filters they were gathered with.
+/// Serializable, so a long crawl can checkpoint it and resume later with
+/// [`MatchupAggregator::with_stats`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct MatchupStats {
+    filters: Filters,
+    games: u32,
+    #[serde(with = "entries")]
+    picks: HashMap<Champion, WinCount>,
+    /// Games in which a champion was banned by either team.
+    #[serde(with = "entries")]
+    bans: HashMap<Champion, u32>,
+    /// Results of the first champion against the second, keyed by their shared position.
+    #[serde(with = "entries")]
+    matchups: HashMap<(Position, Champion, Champion), WinCount>,
+    /// Results of two champions on the same team, the lower champion id first.
+    #[serde(with = "entries")]
+    pairs: HashMap<(Champion, Champion), WinCount>,
+    match_ids: HashSet<String>
+}
+
+impl MatchupStats {
+    pub fn games(&self) -> u32 {
+        self.games
+    }
+
+    pub fn picks(&self, champion: Champion) -> WinCount {
+        self.picks.get(&champion).copied().unwrap_or_default()
+    }
+
+    pub fn pick_rate(&self, champion: Champion) -> f64 {
+        ratio(f64::from(self.picks(champion).games), f64::from(self.games))
+    }
+
+    pub fn ban_rate(&self, champion: Champion) -> f64 {
+        ratio(f64::from(self.bans.get(&champion).copied().unwrap_or(0)), f64::from(self.games))
+    }
+
+    /// Results of `champion` against `opponent` when both played `position`.
+    pub fn matchup(&self, position: Position, champion: Champion, opponent: Champion) -> WinCount {
+        self.matchups.get(&(position, champion, opponent)).copied().unwrap_or_default()
+    }
+
+    /// Results of `first` and `second` playing on the same team.
+    pub fn pair(&self, first: Champion, second: Champion) -> WinCount {
+        self.pairs.get(&(first.min(second), first.max(second))).copied().unwrap_or_default()
+    }
+
+    fn add(&mut self, match_: &MatchDto) {
+        self.games += 1;
+        self.match_ids.insert(match_.metadata.match_id.clone());
+
+        let participants = &match_.info.participants;
+        for participant in participants {
+            self.picks.entry(participant.champion_id).or_default().add(participant.win);
+        }
+
+        let banned: HashSet<_> = match_
+            .info
+            .teams
+            .iter()
+            .flat_map(|team| &team.bans)
+            .map(|ban| ban.champion_id)
+            .filter(|champion| *champion != Champion::NONE)
+            .collect();
+        for champion in banned {
+            *self.bans.entry(champion).or_default() += 1;
+        }
+
+        for (i, participant) in participants.iter().enumerate() {
+            for other in &participants[i + 1..] {
+                if participant.team_id == other.team_id {
+                    let key = (participant.champion_id.min(other.champion_id), participant.champion_id.max(other.champion_id));
+                    self.pairs.entry(key).or_default().add(participant.win);
+                } else if participant.team_position == other.team_position && is_role(&participant.team_position) {
+                    let position = participant.team_position.clone();
+                    self.matchups
+                        .entry((position.clone(), participant.champion_id, other.champion_id))
+                        .or_default()
+                        .add(participant.win);
+                    self.matchups
+                        .entry((position, other.champion_id, participant.champion_id))
+                        .or_default()
+                        .add(other.win);
+                }
+            }
+        }
+    }
+}
+
+fn is_role(position: &Position) -> bool {
+    matches!(position, Position::Top | Position::Jungle | Position::Middle | Position::Bottom | Position::Utility)
+}
+
+/// Aggregates champion, matchup, pair and ban statistics over matches, one at a time. Remakes,
+/// duplicates and matches outside the filters are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchupAggregator {
+    stats: MatchupStats
+}
+
+impl Default for MatchupAggregator {
+    fn default() -> Self {
+        MatchupAggregator::new()
+    }
+}
+
+impl MatchupAggregator {
+    pub fn new() -> Self {
+        MatchupAggregator { stats: MatchupStats::default() }
+    }
+
+    /// Only count matches in one of the given queues.
+    pub fn with_queues(mut self, queues: impl IntoIterator<Item = Queue>) -> Self {
+        self.stats.filters.queues = queues.into_iter().collect();
+        self
+    }
+
+    pub fn with_patches(mut self, patches: impl RangeBounds<Patch>) -> Self {
+        self.stats.filters.patches = (patches.start_bound().cloned(), patches.end_bound().cloned());
+        self
+    }
+
+    /// Only count matches added with [`add_with_tier`](Self::add_with_tier) in one of the given
+    /// tiers. Match data does not include rank, so the tier has to come from the caller.
+    pub fn with_tiers(mut self, tiers: impl IntoIterator<Item = Tier>) -> Self {
+        self.stats.filters.tiers = tiers.into_iter().collect();
+        self
+    }
+
+    /// Resumes from checkpointed stats, counting only matches that pass the filters they were
+    /// gathered with. Filters set before this call are replaced.
+    pub fn with_stats(mut self, stats: MatchupStats) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    pub fn stats(&self) -> &MatchupStats {
+        &self.stats
+    }
+
+    pub fn into_stats(self) -> MatchupStats {
+        self.stats
+    }
+
+    /// Adds a match of unknown rank, returning whether it was counted.
+    pub fn add(&mut self, match_: &MatchDto) -> bool {
+        self.add_match(match_, None)
+    }
+
+    /// Adds a match played at `tier`, returning whether it was counted.
+    pub fn add_with_tier(&mut self, match_: &MatchDto, tier: Tier) -> bool {
+        self.add_match(match_, Some(tier))
+    }
+
+    fn add_match(&mut self, match_: &MatchDto, tier: Option<Tier>) -> bool {
+        if !self.stats.filters.accepts(match_, tier.as_ref())
+            || self.stats.match_ids.contains(&match_.metadata.match_id)
+            || is_remake(&match_.info)
+        {
+            return false;
+        }
+
+        self.stats.add(match_);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn matchups_pairs_and_bans() {
+        let match_ = fixtures::match_();
+        let mut aggregator = MatchupAggregator::new()
+            .with_queues([Queue::RankedSolo5x5])
+            .with_patches(Patch::new(12, 20)..Patch::new(12, 22));
+        assert!(aggregator.add(&match_));
+        assert!(!aggregator.add(&match_));
+
+        let stats = aggregator.stats();
+        assert_eq!(stats.games(), 1);
+        assert_eq!(stats.matchup(Position::Middle, Champion::NEEKO, Champion::AHRI), WinCount { games: 1, wins: 1 });
+        assert_eq!(stats.matchup(Position::Middle, Champion::AHRI, Champion::NEEKO), WinCount { games: 1, wins: 0 });
+        assert_eq!(stats.matchup(Position::Top, Champion::NEEKO, Champion::AHRI), WinCount::default());
+        assert_eq!(stats.pair(Champion::THRESH, Champion::JINX).win_rate(), 1.0);
+        assert_eq!(stats.pair(Champion::NEEKO, Champion::AHRI).games, 0);
+        assert_eq!(stats.pick_rate(Champion::DARIUS), 1.0);
+        assert_eq!(stats.ban_rate(Champion::YASUO), 1.0);
+        assert_eq!(stats.ban_rate(Champion::NEEKO), 0.0);
+    }
+
+    #[test]
+    fn filters() {
+        let match_ = fixtures::match_();
+        assert!(!MatchupAggregator::new().with_queues([Queue::Aram]).add(&match_));
+        assert!(!MatchupAggregator::new().with_patches(Patch::new(13, 1)..).add(&match_));
+
+        let mut unknown_version = match_.clone();
+        unknown_version.info.game_version = "unknown".to_string();
+        assert!(MatchupAggregator::new().add(&unknown_version));
+        assert!(!MatchupAggregator::new().with_patches(..Patch::new(13, 1)).add(&unknown_version));
+
+        let mut ranked = MatchupAggregator::new().with_tiers([Tier::Gold, Tier::Platinum]);
+        assert!(!ranked.add(&match_));
+        assert!(!ranked.add_with_tier(&match_, Tier::Iron));
+        assert!(ranked.add_with_tier(&match_, Tier::Gold));
+    }
+
+    #[test]
+    fn checkpoint() {
+        let match_ = fixtures::match_();
+        let mut aggregator = MatchupAggregator::new().with_queues([Queue::RankedSolo5x5]);
+        aggregator.add(&match_);
+
+        let json = serde_json::to_string(aggregator.stats()).expect("Failed to serialize");
+        let stats: MatchupStats = serde_json::from_str(&json).expect("Failed to deserialize");
+        assert_eq!(&stats, aggregator.stats());
+
+        let mut aram = match_.clone();
+        aram.metadata.match_id = "EUW1_6151255545".to_string();
+        aram.info.queue_id = Queue::Aram;
+
+        let mut resumed = MatchupAggregator::new().with_stats(stats);
+        assert!(!resumed.add(&match_));
+        assert!(!resumed.add(&aram));
+        assert_eq!(resumed.into_stats().matchup(Position::Bottom, Champion::JINX, Champion::CAITLYN).wins, 1);
+    }
+}
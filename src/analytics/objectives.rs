@@ -0,0 +1,285 @@
+use crate::endpoints::constants::{
+    BuildingType, DragonSoul, LaneType, MatchDto, MatchTimelinPosition, MatchTimelineDto,
+    MatchTimelineInfoFrameEvent, MonsterSubType, MonsterType, ObjectiveDto, TowerType
+};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kill {
+    pub time: Duration,
+    /// 0 for executions by turrets, minions or monsters.
+    pub killer_id: i32,
+    pub victim_id: i32,
+    pub assisting_participant_ids: Vec<i32>,
+    pub bounty: i32,
+    pub shutdown_bounty: i32,
+    pub position: MatchTimelinPosition
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonsterKill {
+    pub time: Duration,
+    pub killer_id: i32,
+    pub killer_team_id: i32,
+    pub monster_type: MonsterType,
+    pub monster_sub_type: Option<MonsterSubType>
+}
+
+/// A destroyed turret or inhibitor. `team_id` is the team that lost it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildingKill {
+    pub time: Duration,
+    pub killer_id: i32,
+    pub team_id: i32,
+    pub building_type: BuildingType,
+    pub lane_type: LaneType,
+    pub tower_type: Option<TowerType>
+}
+
+/// A destroyed turret plate. `team_id` is the team that lost it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlateKill {
+    pub time: Duration,
+    pub killer_id: i32,
+    pub team_id: i32,
+    pub lane_type: LaneType
+}
+
+/// Team that destroyed a building of `team_id` on a two-team map.
+fn opponent(team_id: i32) -> i32 {
+    300 - team_id
+}
+
+/// Objectives and kills of a match, in the order they happened.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectiveSummary {
+    pub kill_feed: Vec<Kill>,
+    /// Dragons other than the elder.
+    pub dragons: Vec<MonsterKill>,
+    pub elder_dragons: Vec<MonsterKill>,
+    pub rift_heralds: Vec<MonsterKill>,
+    pub barons: Vec<MonsterKill>,
+    pub towers: Vec<BuildingKill>,
+    pub inhibitors: Vec<BuildingKill>,
+    pub plates: Vec<PlateKill>,
+    /// The team that got a dragon soul, and its type.
+    pub dragon_soul: Option<(i32, DragonSoul)>
+}
+
+impl ObjectiveSummary {
+    pub fn new(timeline: &MatchTimelineDto) -> Self {
+        let mut summary = ObjectiveSummary::default();
+        let events = timeline.info.frames.iter().flat_map(|frame| &frame.events);
+
+        for event in events {
+            let time = event.time();
+            match event {
+                MatchTimelineInfoFrameEvent::ChampionKill {
+                    killer_id, victim_id, assisting_participant_ids, bounty, shutdown_bounty, position, ..
+                } => summary.kill_feed.push(Kill {
+                    time,
+                    killer_id: *killer_id,
+                    victim_id: *victim_id,
+                    assisting_participant_ids: assisting_participant_ids.clone(),
+                    bounty: *bounty,
+                    shutdown_bounty: *shutdown_bounty,
+                    position: position.clone()
+                }),
+                MatchTimelineInfoFrameEvent::EliteMonsterKill { killer_id, killer_team_id, monster_type, monster_sub_type, .. } => {
+                    let kill = MonsterKill {
+                        time,
+                        killer_id: *killer_id,
+                        killer_team_id: *killer_team_id,
+                        monster_type: monster_type.clone(),
+                        monster_sub_type: monster_sub_type.clone()
+                    };
+                    match (monster_type, monster_sub_type) {
+                        (MonsterType::Dragon, Some(MonsterSubType::ElderDragon)) => summary.elder_dragons.push(kill),
+                        (MonsterType::Dragon, _) => summary.dragons.push(kill),
+                        (MonsterType::RiftHerald, _) => summary.rift_heralds.push(kill),
+                        (MonsterType::BaronNashor, _) => summary.barons.push(kill),
+                        _ => {}
+                    }
+                },
+                MatchTimelineInfoFrameEvent::BuildingKill { killer_id, team_id, building_type, lane_type, tower_type, .. } => {
+                    let kill = BuildingKill {
+                        time,
+                        killer_id: *killer_id,
+                        team_id: *team_id,
+                        building_type: building_type.clone(),
+                        lane_type: lane_type.clone(),
+                        tower_type: tower_type.clone()
+                    };
+                    match building_type {
+                        BuildingType::TowerBuilding => summary.towers.push(kill),
+                        BuildingType::InhibitorBuilding => summary.inhibitors.push(kill),
+                        BuildingType::Unknown(_) => {}
+                    }
+                },
+                MatchTimelineInfoFrameEvent::TurretPlateDestroyed { killer_id, team_id, lane_type, .. } => summary.plates.push(PlateKill {
+                    time,
+                    killer_id: *killer_id,
+                    team_id: *team_id,
+                    lane_type: lane_type.clone()
+                }),
+                MatchTimelineInfoFrameEvent::DragonSoulGiven { team_id, name, .. } => summary.dragon_soul = Some((*team_id, name.clone())),
+                _ => {}
+            }
+        }
+
+        summary
+    }
+
+    pub fn first_blood(&self) -> Option<&Kill> {
+        self.kill_feed.first()
+    }
+
+    pub fn first_tower(&self) -> Option<&BuildingKill> {
+        self.towers.first()
+    }
+
+    /// Plates `team_id` destroyed in the top, mid and bot lane.
+    pub fn plates_by_lane(&self, team_id: i32) -> [(LaneType, usize); 3] {
+        [LaneType::TopLane, LaneType::MidLane, LaneType::BotLane].map(|lane| {
+            let plates = self
+                .plates
+                .iter()
+                .filter(|plate| plate.lane_type == lane && opponent(plate.team_id) == team_id)
+                .count();
+            (lane, plates)
+        })
+    }
+
+    /// Compares the summary to the end-of-game objective counts of `match_`, returning every
+    /// objective where they disagree. Elder dragons count as dragons there.
+    pub fn check(&self, match_: &MatchDto) -> Vec<ObjectiveMismatch> {
+        let team_of = |participant_id: i32| {
+            match_
+                .info
+                .participants
+                .iter()
+                .find(|participant| participant.participant_id == participant_id)
+                .map(|participant| participant.team_id)
+        };
+
+        let kills: Vec<_> = self.kill_feed.iter().map(|kill| team_of(kill.killer_id)).collect();
+        let dragons: Vec<_> = self.dragons.iter().chain(&self.elder_dragons).map(|kill| Some(kill.killer_team_id)).collect();
+        let heralds: Vec<_> = self.rift_heralds.iter().map(|kill| Some(kill.killer_team_id)).collect();
+        let barons: Vec<_> = self.barons.iter().map(|kill| Some(kill.killer_team_id)).collect();
+        let towers: Vec<_> = self.towers.iter().map(|kill| Some(opponent(kill.team_id))).collect();
+        let inhibitors: Vec<_> = self.inhibitors.iter().map(|kill| Some(opponent(kill.team_id))).collect();
+
+        let mut mismatches = Vec::new();
+        for team in &match_.info.teams {
+            let objectives = &team.objectives;
+            let comparisons = [
+                ("champion", &kills, &objectives.champion),
+                ("dragon", &dragons, &objectives.dragon),
+                ("riftHerald", &heralds, &objectives.rift_herald),
+                ("baron", &barons, &objectives.baron),
+                ("tower", &towers, &objectives.tower),
+                ("inhibitor", &inhibitors, &objectives.inhibitor)
+            ];
+
+            for (objective, teams, from_match) in comparisons {
+                let from_timeline = ObjectiveDto {
+                    first: teams.first() == Some(&Some(team.team_id)),
+                    kills: teams.iter().filter(|id| **id == Some(team.team_id)).count() as i32
+                };
+                if from_timeline != *from_match {
+                    mismatches.push(ObjectiveMismatch {
+                        team_id: team.team_id,
+                        objective,
+                        from_timeline,
+                        from_match: from_match.clone()
+                    });
+                }
+            }
+        }
+        mismatches
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectiveMismatch {
+    pub team_id: i32,
+    /// Name of the field in `ObjectivesDto`'s JSON.
+    pub objective: &'static str,
+    pub from_timeline: ObjectiveDto,
+    pub from_match: ObjectiveDto
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn summary_of_match() {
+        let timeline = fixtures::timeline();
+        let summary = ObjectiveSummary::new(&timeline);
+
+        let first_blood = summary.first_blood().expect("No first blood");
+        assert_eq!((first_blood.killer_id, first_blood.victim_id), (1, 9));
+        assert_eq!(first_blood.assisting_participant_ids, [2, 3, 4, 5]);
+        assert_eq!(summary.first_tower().map(|tower| (tower.team_id, tower.lane_type.clone())), Some((200, LaneType::BotLane)));
+
+        let dragons: Vec<_> = summary.dragons.iter().map(|dragon| (dragon.killer_team_id, dragon.monster_sub_type.clone())).collect();
+        assert_eq!(dragons, [
+            (100, Some(MonsterSubType::FireDragon)),
+            (100, Some(MonsterSubType::ChemtechDragon)),
+            (200, Some(MonsterSubType::ChemtechDragon)),
+            (100, Some(MonsterSubType::ChemtechDragon))
+        ]);
+        assert_eq!(summary.rift_heralds[0].time, Duration::from_secs(535));
+        assert_eq!(summary.barons.len(), 1);
+        assert_eq!(summary.inhibitors.len(), 1);
+        assert_eq!(summary.plates_by_lane(100), [(LaneType::TopLane, 1), (LaneType::MidLane, 1), (LaneType::BotLane, 2)]);
+        assert_eq!(summary.plates_by_lane(200), [(LaneType::TopLane, 0), (LaneType::MidLane, 1), (LaneType::BotLane, 0)]);
+
+        let shutdowns: Vec<_> = summary.kill_feed.iter().filter(|kill| kill.shutdown_bounty > 0).map(|kill| kill.shutdown_bounty).collect();
+        assert_eq!(shutdowns, [450, 150, 150]);
+        assert_eq!(summary.dragon_soul, None);
+    }
+
+    #[test]
+    fn matches_end_of_game_objectives() {
+        let (mut match_, timeline) = (fixtures::match_(), fixtures::timeline());
+        let summary = ObjectiveSummary::new(&timeline);
+        assert_eq!(summary.check(&match_), []);
+
+        match_.info.teams[1].objectives.dragon.kills = 2;
+        let mismatches = summary.check(&match_);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!((mismatches[0].team_id, mismatches[0].objective, mismatches[0].from_timeline.kills), (200, "dragon", 1));
+    }
+
+    #[test]
+    fn elder_dragon_and_soul() {
+        let mut timeline = fixtures::timeline();
+        let events = &mut timeline.info.frames[24].events;
+        events.push(MatchTimelineInfoFrameEvent::DragonSoulGiven {
+            timestamp: 1_441_000,
+            team_id: 100,
+            name: DragonSoul::Chemtech,
+            extra: Default::default()
+        });
+        events.push(MatchTimelineInfoFrameEvent::EliteMonsterKill {
+            timestamp: 1_441_500,
+            killer_id: 2,
+            killer_team_id: 100,
+            monster_type: MonsterType::Dragon,
+            monster_sub_type: Some(MonsterSubType::ElderDragon),
+            assisting_participant_ids: Vec::new(),
+            bounty: None,
+            position: MatchTimelinPosition { x: 9866, y: 4414 },
+            extra: Default::default()
+        });
+        events.push(MatchTimelineInfoFrameEvent::Unknown(Box::default()));
+
+        let summary = ObjectiveSummary::new(&timeline);
+        assert_eq!(summary.dragon_soul, Some((100, DragonSoul::Chemtech)));
+        assert_eq!(summary.elder_dragons.len(), 1);
+        assert_eq!(summary.dragons.len(), 4);
+    }
+}
@@ -0,0 +1,276 @@
+use super::stats::{is_remake, kda, ratio};
+use crate::champion::Champion;
+use crate::endpoints::constants::{MatchDto, ParticipantDto, Position};
+use std::collections::{HashMap, HashSet};
+
+/// Games, wins and summed kills, deaths and assists over a set of games.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Record {
+    pub games: u32,
+    pub wins: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32
+}
+
+impl Record {
+    fn from_participant(participant: &ParticipantDto) -> Self {
+        Record {
+            games: 1,
+            wins: u32::from(participant.win),
+            kills: participant.kills.max(0) as u32,
+            deaths: participant.deaths.max(0) as u32,
+            assists: participant.assists.max(0) as u32
+        }
+    }
+
+    fn add(&mut self, other: &Record) {
+        self.games += other.games;
+        self.wins += other.wins;
+        self.kills += other.kills;
+        self.deaths += other.deaths;
+        self.assists += other.assists;
+    }
+
+    fn remove(&mut self, other: &Record) {
+        self.games -= other.games;
+        self.wins -= other.wins;
+        self.kills -= other.kills;
+        self.deaths -= other.deaths;
+        self.assists -= other.assists;
+    }
+
+    pub fn losses(&self) -> u32 {
+        self.games - self.wins
+    }
+
+    /// From 0 to 1, or 0 without games.
+    pub fn win_rate(&self) -> f64 {
+        ratio(f64::from(self.wins), f64::from(self.games))
+    }
+
+    /// [`kda`] of the average kills, deaths and assists per game.
+    pub fn kda(&self) -> f64 {
+        kda(self.kills as i32, self.deaths as i32, self.assists as i32)
+    }
+}
+
+/// One counted game, kept so it can be taken out of the aggregates again.
+#[derive(Debug, Clone, PartialEq)]
+struct Game {
+    match_id: String,
+    game_creation: i64,
+    champion: Champion,
+    position: Position,
+    record: Record
+}
+
+/// A player's aggregates over their matches. Matches are added one at a time, so a profile can be
+/// kept around and updated as new matches come in. Remakes, matches without the player and
+/// matches that were already added are skipped. With a window, only the most recent games count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerProfile {
+    pub puuid: String,
+    pub overall: Record,
+    pub champions: HashMap<Champion, Record>,
+    /// Games played per `team_position`.
+    pub roles: HashMap<Position, u32>,
+    window: Option<usize>,
+    /// Every counted game, oldest first.
+    games: Vec<Game>,
+    match_ids: HashSet<String>
+}
+
+impl PlayerProfile {
+    pub fn new(puuid: impl Into<String>) -> Self {
+        PlayerProfile {
+            puuid: puuid.into(),
+            overall: Record::default(),
+            champions: HashMap::new(),
+            roles: HashMap::new(),
+            window: None,
+            games: Vec::new(),
+            match_ids: HashSet::new()
+        }
+    }
+
+    /// Only count the `games` most recent games, dropping older ones as newer ones are added.
+    pub fn with_window(mut self, games: usize) -> Self {
+        self.window = Some(games);
+        self.evict();
+        self
+    }
+
+    pub fn from_matches<'a>(puuid: impl Into<String>, matches: impl IntoIterator<Item = &'a MatchDto>) -> Self {
+        let mut profile = PlayerProfile::new(puuid);
+        for match_ in matches {
+            profile.add(match_);
+        }
+        profile
+    }
+
+    /// Adds a match, returning whether it was counted. A match older than every game in a full
+    /// window is not counted.
+    pub fn add(&mut self, match_: &MatchDto) -> bool {
+        if self.match_ids.contains(&match_.metadata.match_id) || is_remake(&match_.info) {
+            return false;
+        }
+        let Some(participant) = match_.info.participants.iter().find(|participant| participant.puuid == self.puuid) else {
+            return false;
+        };
+
+        let game = Game {
+            match_id: match_.metadata.match_id.clone(),
+            game_creation: match_.info.game_creation,
+            champion: participant.champion_id,
+            position: participant.team_position.clone(),
+            record: Record::from_participant(participant)
+        };
+        self.overall.add(&game.record);
+        self.champions.entry(game.champion).or_default().add(&game.record);
+        *self.roles.entry(game.position.clone()).or_default() += 1;
+        self.match_ids.insert(game.match_id.clone());
+
+        let index = self.games.partition_point(|other| other.game_creation <= game.game_creation);
+        self.games.insert(index, game);
+        self.evict();
+        self.match_ids.contains(&match_.metadata.match_id)
+    }
+
+    /// Takes the oldest games out of the aggregates until the window is no longer exceeded.
+    fn evict(&mut self) {
+        let excess = self.games.len().saturating_sub(self.window.unwrap_or(usize::MAX));
+        for game in self.games.drain(..excess) {
+            self.overall.remove(&game.record);
+            if let Some(record) = self.champions.get_mut(&game.champion) {
+                record.remove(&game.record);
+                if record.games == 0 {
+                    self.champions.remove(&game.champion);
+                }
+            }
+            if let Some(games) = self.roles.get_mut(&game.position) {
+                *games -= 1;
+                if *games == 0 {
+                    self.roles.remove(&game.position);
+                }
+            }
+            self.match_ids.remove(&game.match_id);
+        }
+    }
+
+    pub fn games(&self) -> u32 {
+        self.overall.games
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.overall.win_rate()
+    }
+
+    pub fn kda(&self) -> f64 {
+        self.overall.kda()
+    }
+
+    /// Up to `count` champions with the most games, ties broken by win rate.
+    pub fn most_played(&self, count: usize) -> Vec<(Champion, Record)> {
+        let mut champions: Vec<_> = self.champions.iter().map(|(champion, record)| (*champion, *record)).collect();
+        champions.sort_by(|(a_champion, a), (b_champion, b)| {
+            b.games
+                .cmp(&a.games)
+                .then(b.win_rate().total_cmp(&a.win_rate()))
+                .then(a_champion.cmp(b_champion))
+        });
+        champions.truncate(count);
+        champions
+    }
+
+    /// Share of games played in each role, from 0 to 1, most played first.
+    pub fn role_distribution(&self) -> Vec<(Position, f64)> {
+        let mut roles: Vec<_> = self
+            .roles
+            .iter()
+            .map(|(position, games)| (position.clone(), f64::from(*games) / f64::from(self.games())))
+            .collect();
+        roles.sort_by(|(a_position, a), (b_position, b)| b.total_cmp(a).then_with(|| a_position.as_str().cmp(b_position.as_str())));
+        roles
+    }
+
+    /// Results of the last `count` games, most recent first.
+    pub fn recent_form(&self, count: usize) -> Vec<bool> {
+        self.games.iter().rev().take(count).map(|game| game.record.wins > 0).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn incremental_profile() {
+        let first = fixtures::match_();
+        let puuid = first.info.participants[2].puuid.clone();
+
+        let mut loss = fixtures::match_();
+        loss.metadata.match_id = "EUW1_6151255545".to_string();
+        loss.info.game_creation += 3_600_000;
+        let neeko = &mut loss.info.participants[2];
+        neeko.win = false;
+        neeko.deaths += 4;
+
+        let mut profile = PlayerProfile::from_matches(&puuid, [&loss]);
+        assert!(profile.add(&first));
+        assert!(!profile.add(&first));
+        assert!(!PlayerProfile::new("unknown").add(&first));
+
+        assert_eq!(profile.games(), 2);
+        assert_eq!(profile.win_rate(), 0.5);
+        assert_eq!(profile.recent_form(5), [false, true]);
+        assert_eq!(profile.role_distribution(), [(Position::Middle, 1.0)]);
+
+        let most_played = profile.most_played(3);
+        assert_eq!(most_played.len(), 1);
+        assert_eq!(most_played[0].0, Champion::NEEKO);
+        assert_eq!((most_played[0].1.games, most_played[0].1.wins, most_played[0].1.losses()), (2, 1, 1));
+
+        let participant = &first.info.participants[2];
+        let expected = kda(participant.kills * 2, participant.deaths * 2 + 4, participant.assists * 2);
+        assert_eq!(profile.kda(), expected);
+    }
+
+    #[test]
+    fn windowed_profile() {
+        let mut oldest = fixtures::match_();
+        let puuid = oldest.info.participants[2].puuid.clone();
+        oldest.metadata.match_id = "EUW1_6151255543".to_string();
+        oldest.info.game_creation -= 3_600_000;
+        let neeko = &mut oldest.info.participants[2];
+        neeko.champion_id = Champion::AHRI;
+        neeko.team_position = Position::Top;
+        neeko.win = false;
+
+        let mut latest = fixtures::match_();
+        latest.metadata.match_id = "EUW1_6151255545".to_string();
+        latest.info.game_creation += 3_600_000;
+        latest.info.participants[2].win = false;
+
+        let mut profile = PlayerProfile::from_matches(&puuid, [&oldest, &latest]).with_window(2);
+        assert_eq!(profile.games(), 2);
+        assert!(profile.add(&fixtures::match_()));
+        assert!(!profile.add(&oldest));
+
+        assert_eq!(profile.games(), 2);
+        assert_eq!(profile.win_rate(), 0.5);
+        assert_eq!(profile.recent_form(5), [false, true]);
+        assert_eq!(profile.role_distribution(), [(Position::Middle, 1.0)]);
+        assert_eq!(profile.most_played(3), [(Champion::NEEKO, Record { games: 2, wins: 1, ..profile.overall })]);
+        assert!(!profile.champions.contains_key(&Champion::AHRI));
+    }
+
+    #[test]
+    fn skips_remakes() {
+        let mut remake = fixtures::match_();
+        let puuid = remake.info.participants[0].puuid.clone();
+        remake.info.participants[0].game_ended_in_early_surrender = true;
+        assert!(!PlayerProfile::new(puuid).add(&remake));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::endpoints::constants::{InfoDto, MatchDto, ParticipantDto};
+use std::time::Duration;
+
+/// Whether the game was remade, i.e. ended in an early surrender vote after an AFK. Remakes
+/// do not count as played games, so they have no [`MatchStats`].
+pub fn is_remake(info: &InfoDto) -> bool {
+    info.participants.iter().any(|participant| participant.game_ended_in_early_surrender)
+}
+
+/// `(kills + assists) / deaths`, with deathless games counting as one death so a perfect
+/// game still yields a finite ratio.
+pub fn kda(kills: i32, deaths: i32, assists: i32) -> f64 {
+    f64::from(kills + assists) / f64::from(deaths.max(1))
+}
+
+/// Creep score: lane minions plus jungle monsters.
+pub fn creep_score(participant: &ParticipantDto) -> i32 {
+    participant.total_minions_killed + participant.neutral_minions_killed
+}
+
+/// `numerator / denominator`, or 0 when the denominator is.
+pub(crate) fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator > 0.0 {
+        numerator / denominator
+    } else {
+        0.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticipantStats<'a> {
+    pub participant: &'a ParticipantDto,
+    /// See [`kda`].
+    pub kda: f64,
+    /// Share of the team's kills the participant killed or assisted in, from 0 to 1.
+    pub kill_participation: f64,
+    /// See [`creep_score`].
+    pub creep_score: i32,
+    pub cs_per_minute: f64,
+    pub gold_per_minute: f64,
+    /// Share of the team's damage to champions dealt by the participant, from 0 to 1.
+    pub damage_share: f64,
+    pub vision_per_minute: f64
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamStats {
+    pub team_id: i32,
+    pub win: bool,
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub gold_earned: i32,
+    pub damage_to_champions: i32,
+    pub creep_score: i32,
+    pub vision_score: i32,
+    pub kda: f64,
+    pub gold_per_minute: f64
+}
+
+/// Metrics derived from a match. Per-minute values are relative to
+/// [`InfoDto::game_length`], and every ratio is 0 where its denominator is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchStats<'a> {
+    pub game_length: Duration,
+    pub participants: Vec<ParticipantStats<'a>>,
+    pub teams: Vec<TeamStats>
+}
+
+impl<'a> MatchStats<'a> {
+    /// `None` if the match was a remake.
+    pub fn new(match_: &'a MatchDto) -> Option<Self> {
+        let info = &match_.info;
+        if is_remake(info) {
+            return None;
+        }
+
+        let game_length = info.game_length();
+        let minutes = game_length.as_secs_f64() / 60.0;
+
+        let mut teams: Vec<TeamStats> = Vec::new();
+        for participant in &info.participants {
+            let index = match teams.iter().position(|team| team.team_id == participant.team_id) {
+                Some(index) => index,
+                None => {
+                    teams.push(TeamStats::empty(participant.team_id, participant.win));
+                    teams.len() - 1
+                }
+            };
+            teams[index].add(participant);
+        }
+        for team in &mut teams {
+            team.kda = kda(team.kills, team.deaths, team.assists);
+            team.gold_per_minute = ratio(f64::from(team.gold_earned), minutes);
+        }
+
+        let participants = info
+            .participants
+            .iter()
+            .map(|participant| {
+                let team = teams
+                    .iter()
+                    .find(|team| team.team_id == participant.team_id)
+                    .expect("Every participant has a team");
+                let creep_score = creep_score(participant);
+
+                ParticipantStats {
+                    participant,
+                    kda: kda(participant.kills, participant.deaths, participant.assists),
+                    kill_participation: ratio(f64::from(participant.kills + participant.assists), f64::from(team.kills)),
+                    creep_score,
+                    cs_per_minute: ratio(f64::from(creep_score), minutes),
+                    gold_per_minute: ratio(f64::from(participant.gold_earned), minutes),
+                    damage_share: ratio(f64::from(participant.total_damage_dealt_to_champions), f64::from(team.damage_to_champions)),
+                    vision_per_minute: ratio(f64::from(participant.vision_score), minutes)
+                }
+            })
+            .collect();
+
+        Some(MatchStats { game_length, participants, teams })
+    }
+
+    pub fn participant(&self, puuid: &str) -> Option<&ParticipantStats<'a>> {
+        self.participants.iter().find(|stats| stats.participant.puuid == puuid)
+    }
+
+    pub fn team(&self, team_id: i32) -> Option<&TeamStats> {
+        self.teams.iter().find(|team| team.team_id == team_id)
+    }
+}
+
+impl TeamStats {
+    fn empty(team_id: i32, win: bool) -> Self {
+        TeamStats {
+            team_id,
+            win,
+            kills: 0,
+            deaths: 0,
+            assists: 0,
+            gold_earned: 0,
+            damage_to_champions: 0,
+            creep_score: 0,
+            vision_score: 0,
+            kda: 0.0,
+            gold_per_minute: 0.0
+        }
+    }
+
+    fn add(&mut self, participant: &ParticipantDto) {
+        self.kills += participant.kills;
+        self.deaths += participant.deaths;
+        self.assists += participant.assists;
+        self.gold_earned += participant.gold_earned;
+        self.damage_to_champions += participant.total_damage_dealt_to_champions;
+        self.creep_score += creep_score(participant);
+        self.vision_score += participant.vision_score;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn participant_stats() {
+        let match_ = fixtures::match_();
+        let stats = MatchStats::new(&match_).expect("Not a remake");
+        let darius = stats.participant(&match_.metadata.participants[0]).expect("Missing participant");
+
+        // 5/1/7 in a 25:30 game where the team got 21 kills
+        assert_close(darius.kda, 12.0);
+        assert_close(darius.kill_participation, 12.0 / 21.0);
+        assert_eq!(darius.creep_score, 184);
+        assert_close(darius.cs_per_minute, 184.0 / 25.5);
+        assert_close(darius.gold_per_minute, 10024.0 / 25.5);
+        assert_close(darius.damage_share, 12918.0 / 76505.0);
+        assert_close(darius.vision_per_minute, 18.0 / 25.5);
+
+        // Jungle camps count towards creep score
+        assert_eq!(stats.participants[1].creep_score, 109);
+    }
+
+    #[test]
+    fn team_stats() {
+        let match_ = fixtures::match_();
+        let stats = MatchStats::new(&match_).expect("Not a remake");
+        let blue = stats.team(100).expect("Missing team");
+
+        assert!(blue.win);
+        assert_eq!(blue.kills, 21);
+        assert_eq!(blue.damage_to_champions, 76505);
+
+        let shares: f64 = stats.participants.iter().filter(|stats| stats.participant.team_id == 100).map(|stats| stats.damage_share).sum();
+        assert_close(shares, 1.0);
+    }
+
+    #[test]
+    fn deathless_and_remade_games() {
+        assert_close(kda(3, 0, 4), 7.0);
+
+        let mut match_ = fixtures::match_();
+        for participant in &mut match_.info.participants {
+            participant.game_ended_in_early_surrender = true;
+        }
+        assert!(is_remake(&match_.info));
+        assert_eq!(MatchStats::new(&match_), None);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::endpoints::constants::{MatchDto, MatchTimelineDto, MatchTimelineInfoFrame, MatchTimelineInfoFrameParticipantFrame, Position};
+use std::time::Duration;
+
+pub const BLUE_TEAM: i32 = 100;
+pub const RED_TEAM: i32 = 200;
+
+/// The minutes lane matchups are usually compared at.
+pub const LANE_DIFF_MINUTES: [u32; 3] = [10, 15, 20];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TeamTotals {
+    pub gold: i32,
+    pub xp: i32,
+    pub creep_score: i32
+}
+
+/// Team totals at one timeline frame. Differences are blue minus red, so a positive
+/// difference is a blue side lead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamFrame {
+    pub time: Duration,
+    pub blue: TeamTotals,
+    pub red: TeamTotals
+}
+
+impl TeamFrame {
+    pub fn gold_diff(&self) -> i32 {
+        self.blue.gold - self.red.gold
+    }
+
+    pub fn xp_diff(&self) -> i32 {
+        self.blue.xp - self.red.xp
+    }
+
+    pub fn cs_diff(&self) -> i32 {
+        self.blue.creep_score - self.red.creep_score
+    }
+}
+
+/// Total gold, XP and creep score of both teams at every frame of the timeline: once a
+/// minute, plus a last frame when the game ended.
+pub fn team_frames(match_: &MatchDto, timeline: &MatchTimelineDto) -> Vec<TeamFrame> {
+    timeline
+        .info
+        .frames
+        .iter()
+        .map(|frame| {
+            let mut team_frame = TeamFrame {
+                time: frame.time(),
+                blue: TeamTotals::default(),
+                red: TeamTotals::default()
+            };
+
+            for participant in &match_.info.participants {
+                let Some(stats) = frame.participant_frames.get(participant.participant_id) else { continue };
+                let totals = match participant.team_id {
+                    BLUE_TEAM => &mut team_frame.blue,
+                    RED_TEAM => &mut team_frame.red,
+                    _ => continue
+                };
+                totals.gold += stats.total_gold;
+                totals.xp += stats.xp;
+                totals.creep_score += creep_score(stats);
+            }
+
+            team_frame
+        })
+        .collect()
+}
+
+fn creep_score(frame: &MatchTimelineInfoFrameParticipantFrame) -> i32 {
+    frame.minions_killed + frame.jungle_minions_killed
+}
+
+/// A blue side participant and their red side counterpart in the same position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaneOpponents {
+    pub position: Position,
+    pub blue_participant_id: i32,
+    pub red_participant_id: i32
+}
+
+/// Pairs participants by `team_position`. Positions that are empty or not filled by exactly one
+/// player per team, as in remakes or custom games, are left out.
+pub fn lane_opponents(match_: &MatchDto) -> Vec<LaneOpponents> {
+    let positions = [Position::Top, Position::Jungle, Position::Middle, Position::Bottom, Position::Utility];
+    let participants = &match_.info.participants;
+
+    positions
+        .into_iter()
+        .filter_map(|position| {
+            let in_position = |team_id| {
+                let mut ids = participants
+                    .iter()
+                    .filter(|participant| participant.team_id == team_id && participant.team_position == position)
+                    .map(|participant| participant.participant_id);
+                match (ids.next(), ids.next()) {
+                    (Some(id), None) => Some(id),
+                    _ => None
+                }
+            };
+
+            Some(LaneOpponents {
+                blue_participant_id: in_position(BLUE_TEAM)?,
+                red_participant_id: in_position(RED_TEAM)?,
+                position
+            })
+        })
+        .collect()
+}
+
+/// Difference between lane opponents at a given minute, blue minus red.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaneDiff {
+    pub opponents: LaneOpponents,
+    pub minute: u32,
+    pub gold_diff: i32,
+    pub xp_diff: i32,
+    pub cs_diff: i32,
+    pub level_diff: i32
+}
+
+/// Lane differences at `minute`, empty if the game ended before it.
+pub fn lane_diffs(match_: &MatchDto, timeline: &MatchTimelineDto, minute: u32) -> Vec<LaneDiff> {
+    let Some(frame) = frame_at_minute(timeline, minute) else { return Vec::new() };
+
+    lane_opponents(match_)
+        .into_iter()
+        .filter_map(|opponents| {
+            let blue = frame.participant_frames.get(opponents.blue_participant_id)?;
+            let red = frame.participant_frames.get(opponents.red_participant_id)?;
+
+            Some(LaneDiff {
+                minute,
+                gold_diff: blue.total_gold - red.total_gold,
+                xp_diff: blue.xp - red.xp,
+                cs_diff: creep_score(blue) - creep_score(red),
+                level_diff: blue.level - red.level,
+                opponents
+            })
+        })
+        .collect()
+}
+
+/// The regular frame taken at `minute`. The last frame, taken when the game ended, only
+/// counts for the minute it falls on.
+fn frame_at_minute(timeline: &MatchTimelineDto, minute: u32) -> Option<&MatchTimelineInfoFrame> {
+    let interval = timeline.info.frame_interval().max(Duration::from_secs(1));
+    let target = Duration::from_secs(u64::from(minute) * 60);
+
+    timeline
+        .info
+        .frames
+        .iter()
+        .find(|frame| frame.time() >= target && frame.time() < target + interval)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn team_gold_and_xp_curves() {
+        let (match_, timeline) = (fixtures::match_(), fixtures::timeline());
+        let frames = team_frames(&match_, &timeline);
+
+        assert_eq!(frames.len(), timeline.info.frames.len());
+        assert_eq!(frames[0].time, Duration::ZERO);
+        assert_eq!(frames.last().map(|frame| frame.time), Some(Duration::from_millis(1530412)));
+
+        let at_10 = &frames[10];
+        assert_eq!(at_10.blue.gold, 3145 + 3269 + 3165 + 3125 + 2190);
+        assert_eq!(at_10.red.gold, 2829 + 2508 + 2649 + 2685 + 1990);
+        assert_eq!(at_10.gold_diff(), at_10.blue.gold - at_10.red.gold);
+        assert_eq!(at_10.xp_diff(), 4 * (5288 - 4655) + (4229 - 3720));
+        assert_eq!(at_10.cs_diff(), (65 + 45 + 66 + 64) - (65 + 30 + 55 + 57));
+    }
+
+    #[test]
+    fn lane_opponent_diffs() {
+        let (match_, timeline) = (fixtures::match_(), fixtures::timeline());
+        let opponents = lane_opponents(&match_);
+
+        assert_eq!(opponents.len(), 5);
+        assert_eq!(opponents[2], LaneOpponents { position: Position::Middle, blue_participant_id: 3, red_participant_id: 8 });
+
+        let diffs = lane_diffs(&match_, &timeline, 10);
+        let mid = diffs.iter().find(|diff| diff.opponents.position == Position::Middle).expect("Missing mid lane");
+        assert_eq!((mid.gold_diff, mid.xp_diff, mid.cs_diff, mid.level_diff), (516, 633, 11, 0));
+
+        for minute in LANE_DIFF_MINUTES {
+            assert_eq!(lane_diffs(&match_, &timeline, minute).len(), 5);
+        }
+        assert!(lane_diffs(&match_, &timeline, 26).is_empty());
+    }
+
+    #[test]
+    fn unpaired_positions() {
+        let mut match_ = fixtures::match_();
+        match_.info.participants[0].team_position = Position::Unkown;
+        match_.info.participants[1].team_position = Position::Middle;
+
+        let positions: Vec<_> = lane_opponents(&match_).into_iter().map(|opponents| opponents.position).collect();
+        assert_eq!(positions, [Position::Bottom, Position::Utility]);
+    }
+}
@@ -0,0 +1,555 @@
+id_enum! {
+    /// Queues from Riot's `queues.json`. Retired queue ids that were later reused under a new
+    /// id carry a `Legacy` suffix.
+    pub enum Queue {
+        Custom => 0,
+        BlindPick5x5Legacy => 2,
+        RankedSolo5x5Legacy => 4,
+        RankedPremade5x5 => 6,
+        CoopVsAiLegacy => 7,
+        Normal3x3 => 8,
+        RankedFlex3x3Legacy => 9,
+        DraftPick5x5Legacy => 14,
+        DominionBlindPick => 16,
+        DominionDraftPick => 17,
+        DominionCoopVsAi => 25,
+        CoopVsAiIntroLegacy => 31,
+        CoopVsAiBeginnerLegacy => 32,
+        CoopVsAiIntermediateLegacy => 33,
+        RankedTeam3x3 => 41,
+        RankedTeam5x5 => 42,
+        CoopVsAi3x3 => 52,
+        TeamBuilder5x5 => 61,
+        AramLegacy => 65,
+        AramCoopVsAi => 67,
+        OneForAllLegacy => 70,
+        SnowdownShowdown1x1 => 72,
+        SnowdownShowdown2x2 => 73,
+        Hexakill6x6 => 75,
+        UltraRapidFire => 76,
+        OneForAllMirror => 78,
+        UltraRapidFireCoopVsAi => 83,
+        DoomBotsRank1 => 91,
+        DoomBotsRank2 => 92,
+        DoomBotsRank5 => 93,
+        AscensionLegacy => 96,
+        Hexakill6x6TwistedTreeline => 98,
+        ButchersBridgeAram => 100,
+        LegendOfThePoroKingLegacy => 300,
+        Nemesis => 310,
+        BlackMarketBrawlers => 313,
+        NexusSiegeLegacy => 315,
+        DefinitelyNotDominion => 317,
+        AllRandomUrfLegacy => 318,
+        AllRandom => 325,
+        DraftPick5x5 => 400,
+        RankedDynamic5x5 => 410,
+        RankedSolo5x5 => 420,
+        BlindPick5x5 => 430,
+        RankedFlex5x5 => 440,
+        Aram => 450,
+        BlindPick3x3 => 460,
+        RankedFlex3x3 => 470,
+        Swiftplay => 480,
+        Quickplay => 490,
+        BloodHuntAssassin => 600,
+        DarkStarSingularity => 610,
+        Clash => 700,
+        AramClash => 720,
+        CoopVsAiIntermediate3x3 => 800,
+        CoopVsAiIntro3x3 => 810,
+        CoopVsAiBeginner3x3 => 820,
+        CoopVsAiIntro => 830,
+        CoopVsAiBeginner => 840,
+        CoopVsAiIntermediate => 850,
+        CoopVsAiIntroBot => 870,
+        CoopVsAiBeginnerBot => 880,
+        CoopVsAiIntermediateBot => 890,
+        AllRandomUrf => 900,
+        Ascension => 910,
+        LegendOfThePoroKing => 920,
+        NexusSiege => 940,
+        DoomBotsVoting => 950,
+        DoomBotsStandard => 960,
+        StarGuardianNormal => 980,
+        StarGuardianOnslaught => 990,
+        ProjectHunters => 1000,
+        SnowAllRandomUrf => 1010,
+        OneForAll => 1020,
+        OdysseyIntro => 1030,
+        OdysseyCadet => 1040,
+        OdysseyCrewmember => 1050,
+        OdysseyCaptain => 1060,
+        OdysseyOnslaught => 1070,
+        TeamfightTactics => 1090,
+        RankedTeamfightTactics => 1100,
+        TeamfightTacticsTutorial => 1110,
+        TeamfightTacticsTest => 1111,
+        NexusBlitzLegacy => 1200,
+        NexusBlitz => 1300,
+        UltimateSpellbook => 1400,
+        Arena => 1700,
+        Arena16 => 1710,
+        Swarm1Player => 1810,
+        Swarm2Players => 1820,
+        Swarm3Players => 1830,
+        Swarm4Players => 1840,
+        PickUrf => 1900,
+        Tutorial1 => 2000,
+        Tutorial2 => 2010,
+        Tutorial3 => 2020,
+        Brawl => 2300
+    }
+}
+
+impl Queue {
+    /// The map the queue is played on, `None` for custom games.
+    pub fn map(&self) -> Option<Map> {
+        let map = match self {
+            Queue::BlindPick5x5Legacy
+            | Queue::RankedSolo5x5Legacy
+            | Queue::RankedPremade5x5
+            | Queue::CoopVsAiLegacy
+            | Queue::DraftPick5x5Legacy
+            | Queue::CoopVsAiIntroLegacy
+            | Queue::CoopVsAiBeginnerLegacy
+            | Queue::CoopVsAiIntermediateLegacy
+            | Queue::RankedTeam5x5
+            | Queue::TeamBuilder5x5
+            | Queue::OneForAllLegacy
+            | Queue::Hexakill6x6
+            | Queue::UltraRapidFire
+            | Queue::UltraRapidFireCoopVsAi
+            | Queue::DoomBotsRank1
+            | Queue::DoomBotsRank2
+            | Queue::DoomBotsRank5
+            | Queue::Nemesis
+            | Queue::BlackMarketBrawlers
+            | Queue::NexusSiegeLegacy
+            | Queue::AllRandomUrfLegacy
+            | Queue::AllRandom
+            | Queue::DraftPick5x5
+            | Queue::RankedDynamic5x5
+            | Queue::RankedSolo5x5
+            | Queue::BlindPick5x5
+            | Queue::RankedFlex5x5
+            | Queue::Swiftplay
+            | Queue::Quickplay
+            | Queue::BloodHuntAssassin
+            | Queue::Clash
+            | Queue::CoopVsAiIntro
+            | Queue::CoopVsAiBeginner
+            | Queue::CoopVsAiIntermediate
+            | Queue::CoopVsAiIntroBot
+            | Queue::CoopVsAiBeginnerBot
+            | Queue::CoopVsAiIntermediateBot
+            | Queue::AllRandomUrf
+            | Queue::NexusSiege
+            | Queue::DoomBotsVoting
+            | Queue::DoomBotsStandard
+            | Queue::SnowAllRandomUrf
+            | Queue::OneForAll
+            | Queue::UltimateSpellbook
+            | Queue::PickUrf
+            | Queue::Tutorial1
+            | Queue::Tutorial2
+            | Queue::Tutorial3 => Map::SummonersRift,
+            Queue::Normal3x3 => Map::TwistedTreelineOriginal,
+            Queue::RankedFlex3x3Legacy
+            | Queue::RankedTeam3x3
+            | Queue::CoopVsAi3x3
+            | Queue::Hexakill6x6TwistedTreeline
+            | Queue::BlindPick3x3
+            | Queue::RankedFlex3x3
+            | Queue::CoopVsAiIntermediate3x3
+            | Queue::CoopVsAiIntro3x3
+            | Queue::CoopVsAiBeginner3x3 => Map::TwistedTreeline,
+            Queue::DominionBlindPick
+            | Queue::DominionDraftPick
+            | Queue::DominionCoopVsAi
+            | Queue::AscensionLegacy
+            | Queue::DefinitelyNotDominion
+            | Queue::Ascension => Map::CrystalScar,
+            Queue::AramLegacy
+            | Queue::AramCoopVsAi
+            | Queue::SnowdownShowdown1x1
+            | Queue::SnowdownShowdown2x2
+            | Queue::OneForAllMirror
+            | Queue::LegendOfThePoroKingLegacy
+            | Queue::Aram
+            | Queue::AramClash
+            | Queue::LegendOfThePoroKing => Map::HowlingAbyss,
+            Queue::ButchersBridgeAram => Map::ButchersBridge,
+            Queue::DarkStarSingularity => Map::CosmicRuins,
+            Queue::StarGuardianNormal
+            | Queue::StarGuardianOnslaught => Map::ValoranCityPark,
+            Queue::ProjectHunters => Map::Overcharge,
+            Queue::OdysseyIntro
+            | Queue::OdysseyCadet
+            | Queue::OdysseyCrewmember
+            | Queue::OdysseyCaptain
+            | Queue::OdysseyOnslaught => Map::CrashSite,
+            Queue::TeamfightTactics
+            | Queue::RankedTeamfightTactics
+            | Queue::TeamfightTacticsTutorial
+            | Queue::TeamfightTacticsTest => Map::Convergence,
+            Queue::NexusBlitzLegacy
+            | Queue::NexusBlitz => Map::NexusBlitz,
+            Queue::Arena
+            | Queue::Arena16 => Map::RingsOfWrath,
+            Queue::Swarm1Player
+            | Queue::Swarm2Players
+            | Queue::Swarm3Players
+            | Queue::Swarm4Players => Map::Swarm,
+            Queue::Brawl => Map::Bandlewood,
+            Queue::Custom | Queue::Unknown(_) => return None
+        };
+        Some(map)
+    }
+
+    pub fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            Queue::BlindPick5x5Legacy => "5v5 Blind Pick games",
+            Queue::RankedSolo5x5Legacy => "5v5 Ranked Solo games",
+            Queue::RankedPremade5x5 => "5v5 Ranked Premade games",
+            Queue::CoopVsAiLegacy => "Co-op vs AI games",
+            Queue::Normal3x3 => "3v3 Normal games",
+            Queue::RankedFlex3x3Legacy => "3v3 Ranked Flex games",
+            Queue::DraftPick5x5Legacy => "5v5 Draft Pick games",
+            Queue::DominionBlindPick => "5v5 Dominion Blind Pick games",
+            Queue::DominionDraftPick => "5v5 Dominion Draft Pick games",
+            Queue::DominionCoopVsAi => "Dominion Co-op vs AI games",
+            Queue::CoopVsAiIntroLegacy => "Co-op vs AI Intro Bot games",
+            Queue::CoopVsAiBeginnerLegacy => "Co-op vs AI Beginner Bot games",
+            Queue::CoopVsAiIntermediateLegacy => "Co-op vs AI Intermediate Bot games",
+            Queue::RankedTeam3x3 => "3v3 Ranked Team games",
+            Queue::RankedTeam5x5 => "5v5 Ranked Team games",
+            Queue::CoopVsAi3x3 => "Co-op vs AI games",
+            Queue::TeamBuilder5x5 => "5v5 Team Builder games",
+            Queue::AramLegacy => "5v5 ARAM games",
+            Queue::AramCoopVsAi => "ARAM Co-op vs AI games",
+            Queue::OneForAllLegacy => "One for All games",
+            Queue::SnowdownShowdown1x1 => "1v1 Snowdown Showdown games",
+            Queue::SnowdownShowdown2x2 => "2v2 Snowdown Showdown games",
+            Queue::Hexakill6x6 => "6v6 Hexakill games",
+            Queue::UltraRapidFire => "Ultra Rapid Fire games",
+            Queue::OneForAllMirror => "One For All: Mirror Mode games",
+            Queue::UltraRapidFireCoopVsAi => "Co-op vs AI Ultra Rapid Fire games",
+            Queue::DoomBotsRank1 => "Doom Bots Rank 1 games",
+            Queue::DoomBotsRank2 => "Doom Bots Rank 2 games",
+            Queue::DoomBotsRank5 => "Doom Bots Rank 5 games",
+            Queue::AscensionLegacy => "Ascension games",
+            Queue::Hexakill6x6TwistedTreeline => "6v6 Hexakill games",
+            Queue::ButchersBridgeAram => "5v5 ARAM games",
+            Queue::LegendOfThePoroKingLegacy => "Legend of the Poro King games",
+            Queue::Nemesis => "Nemesis games",
+            Queue::BlackMarketBrawlers => "Black Market Brawlers games",
+            Queue::NexusSiegeLegacy => "Nexus Siege games",
+            Queue::DefinitelyNotDominion => "Definitely Not Dominion games",
+            Queue::AllRandomUrfLegacy => "ARURF games",
+            Queue::AllRandom => "All Random games",
+            Queue::DraftPick5x5 => "5v5 Draft Pick games",
+            Queue::RankedDynamic5x5 => "5v5 Ranked Dynamic games",
+            Queue::RankedSolo5x5 => "5v5 Ranked Solo games",
+            Queue::BlindPick5x5 => "5v5 Blind Pick games",
+            Queue::RankedFlex5x5 => "5v5 Ranked Flex games",
+            Queue::Aram => "5v5 ARAM games",
+            Queue::BlindPick3x3 => "3v3 Blind Pick games",
+            Queue::RankedFlex3x3 => "3v3 Ranked Flex games",
+            Queue::Swiftplay => "Swiftplay Games",
+            Queue::Quickplay => "Normal (Quickplay)",
+            Queue::BloodHuntAssassin => "Blood Hunt Assassin games",
+            Queue::DarkStarSingularity => "Dark Star: Singularity games",
+            Queue::Clash => "Summoner's Rift Clash games",
+            Queue::AramClash => "ARAM Clash games",
+            Queue::CoopVsAiIntermediate3x3 => "Co-op vs. AI Intermediate Bot games",
+            Queue::CoopVsAiIntro3x3 => "Co-op vs. AI Intro Bot games",
+            Queue::CoopVsAiBeginner3x3 => "Co-op vs. AI Beginner Bot games",
+            Queue::CoopVsAiIntro => "Co-op vs. AI Intro Bot games",
+            Queue::CoopVsAiBeginner => "Co-op vs. AI Beginner Bot games",
+            Queue::CoopVsAiIntermediate => "Co-op vs. AI Intermediate Bot games",
+            Queue::CoopVsAiIntroBot => "Co-op vs. AI Intro Bot games",
+            Queue::CoopVsAiBeginnerBot => "Co-op vs. AI Beginner Bot games",
+            Queue::CoopVsAiIntermediateBot => "Co-op vs. AI Intermediate Bot games",
+            Queue::AllRandomUrf => "ARURF games",
+            Queue::Ascension => "Ascension games",
+            Queue::LegendOfThePoroKing => "Legend of the Poro King games",
+            Queue::NexusSiege => "Nexus Siege games",
+            Queue::DoomBotsVoting => "Doom Bots Voting games",
+            Queue::DoomBotsStandard => "Doom Bots Standard games",
+            Queue::StarGuardianNormal => "Star Guardian Invasion: Normal games",
+            Queue::StarGuardianOnslaught => "Star Guardian Invasion: Onslaught games",
+            Queue::ProjectHunters => "PROJECT: Hunters games",
+            Queue::SnowAllRandomUrf => "Snow ARURF games",
+            Queue::OneForAll => "One for All games",
+            Queue::OdysseyIntro => "Odyssey Extraction: Intro games",
+            Queue::OdysseyCadet => "Odyssey Extraction: Cadet games",
+            Queue::OdysseyCrewmember => "Odyssey Extraction: Crewmember games",
+            Queue::OdysseyCaptain => "Odyssey Extraction: Captain games",
+            Queue::OdysseyOnslaught => "Odyssey Extraction: Onslaught games",
+            Queue::TeamfightTactics => "Teamfight Tactics games",
+            Queue::RankedTeamfightTactics => "Ranked Teamfight Tactics games",
+            Queue::TeamfightTacticsTutorial => "Teamfight Tactics Tutorial games",
+            Queue::TeamfightTacticsTest => "Teamfight Tactics test games",
+            Queue::NexusBlitzLegacy => "Nexus Blitz games",
+            Queue::NexusBlitz => "Nexus Blitz games",
+            Queue::UltimateSpellbook => "Ultimate Spellbook games",
+            Queue::Arena => "Arena",
+            Queue::Arena16 => "Arena",
+            Queue::Swarm1Player => "Swarm Mode Games",
+            Queue::Swarm2Players => "Swarm Mode Games",
+            Queue::Swarm3Players => "Swarm Mode Games",
+            Queue::Swarm4Players => "Swarm Mode Games",
+            Queue::PickUrf => "Pick URF games",
+            Queue::Tutorial1 => "Tutorial 1",
+            Queue::Tutorial2 => "Tutorial 2",
+            Queue::Tutorial3 => "Tutorial 3",
+            Queue::Brawl => "Brawl",
+            Queue::Custom | Queue::Unknown(_) => return None
+        };
+        Some(description)
+    }
+
+    /// Whether Riot has retired the queue, usually in favour of a newer queue id.
+    pub fn is_deprecated(&self) -> bool {
+        matches!(
+            self,
+            Queue::BlindPick5x5Legacy
+                | Queue::RankedSolo5x5Legacy
+                | Queue::RankedPremade5x5
+                | Queue::CoopVsAiLegacy
+                | Queue::Normal3x3
+                | Queue::RankedFlex3x3Legacy
+                | Queue::DraftPick5x5Legacy
+                | Queue::DominionBlindPick
+                | Queue::DominionDraftPick
+                | Queue::DominionCoopVsAi
+                | Queue::CoopVsAiIntroLegacy
+                | Queue::CoopVsAiBeginnerLegacy
+                | Queue::CoopVsAiIntermediateLegacy
+                | Queue::RankedTeam3x3
+                | Queue::RankedTeam5x5
+                | Queue::CoopVsAi3x3
+                | Queue::TeamBuilder5x5
+                | Queue::AramLegacy
+                | Queue::AramCoopVsAi
+                | Queue::OneForAllLegacy
+                | Queue::DoomBotsRank1
+                | Queue::DoomBotsRank2
+                | Queue::DoomBotsRank5
+                | Queue::AscensionLegacy
+                | Queue::LegendOfThePoroKingLegacy
+                | Queue::NexusSiegeLegacy
+                | Queue::AllRandomUrfLegacy
+                | Queue::RankedDynamic5x5
+                | Queue::BlindPick3x3
+                | Queue::RankedFlex3x3
+                | Queue::CoopVsAiIntermediate3x3
+                | Queue::CoopVsAiIntro3x3
+                | Queue::CoopVsAiBeginner3x3
+                | Queue::CoopVsAiIntro
+                | Queue::CoopVsAiBeginner
+                | Queue::CoopVsAiIntermediate
+                | Queue::NexusBlitzLegacy
+        )
+    }
+}
+
+id_enum! {
+    pub enum Map {
+        SummonersRiftSummer => 1,
+        SummonersRiftAutumn => 2,
+        ProvingGrounds => 3,
+        TwistedTreelineOriginal => 4,
+        CrystalScar => 8,
+        TwistedTreeline => 10,
+        SummonersRift => 11,
+        HowlingAbyss => 12,
+        ButchersBridge => 14,
+        CosmicRuins => 16,
+        ValoranCityPark => 18,
+        Overcharge => 19,
+        CrashSite => 20,
+        NexusBlitz => 21,
+        Convergence => 22,
+        RingsOfWrath => 30,
+        Swarm => 33,
+        Bandlewood => 35
+    }
+}
+
+impl Map {
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self {
+            Map::SummonersRiftSummer | Map::SummonersRiftAutumn | Map::SummonersRift => "Summoner's Rift",
+            Map::ProvingGrounds => "The Proving Grounds",
+            Map::TwistedTreelineOriginal | Map::TwistedTreeline => "Twisted Treeline",
+            Map::CrystalScar => "The Crystal Scar",
+            Map::HowlingAbyss => "Howling Abyss",
+            Map::ButchersBridge => "Butcher's Bridge",
+            Map::CosmicRuins => "Cosmic Ruins",
+            Map::ValoranCityPark => "Valoran City Park",
+            Map::Overcharge => "Substructure 43",
+            Map::CrashSite => "Crash Site",
+            Map::NexusBlitz => "Nexus Blitz",
+            Map::Convergence => "Convergence",
+            Map::RingsOfWrath => "Rings of Wrath",
+            Map::Swarm => "Swarm",
+            Map::Bandlewood => "The Bandlewood",
+            Map::Unknown(_) => return None
+        };
+        Some(name)
+    }
+
+    pub fn notes(&self) -> Option<&'static str> {
+        let notes = match self {
+            Map::SummonersRiftSummer => "Original Summer variant",
+            Map::SummonersRiftAutumn => "Original Autumn variant",
+            Map::ProvingGrounds => "Tutorial Map",
+            Map::TwistedTreelineOriginal => "Original Version",
+            Map::CrystalScar => "Dominion map",
+            Map::TwistedTreeline => "Last TT map",
+            Map::SummonersRift => "Current Version",
+            Map::HowlingAbyss => "ARAM map",
+            Map::ButchersBridge => "Alternate ARAM map",
+            Map::CosmicRuins => "Dark Star: Singularity map",
+            Map::ValoranCityPark => "Star Guardian Invasion map",
+            Map::Overcharge => "PROJECT: Hunters map",
+            Map::CrashSite => "Odyssey: Extraction map",
+            Map::NexusBlitz => "Nexus Blitz map",
+            Map::Convergence => "Teamfight Tactics map",
+            Map::RingsOfWrath => "Arena map",
+            Map::Swarm => "Swarm map",
+            Map::Bandlewood => "Brawl map",
+            Map::Unknown(_) => return None
+        };
+        Some(notes)
+    }
+}
+
+string_enum! {
+    pub enum GameMode {
+        Classic => "CLASSIC",
+        Odin => "ODIN",
+        Aram => "ARAM",
+        Tutorial => "TUTORIAL",
+        Urf => "URF",
+        DoomBotsTeemo => "DOOMBOTSTEEMO",
+        OneForAll => "ONEFORALL",
+        Ascension => "ASCENSION",
+        FirstBlood => "FIRSTBLOOD",
+        KingPoro => "KINGPORO",
+        Siege => "SIEGE",
+        Assassinate => "ASSASSINATE",
+        Arsr => "ARSR",
+        DarkStar => "DARKSTAR",
+        StarGuardian => "STARGUARDIAN",
+        Project => "PROJECT",
+        GameModeX => "GAMEMODEX",
+        Odyssey => "ODYSSEY",
+        NexusBlitz => "NEXUSBLITZ",
+        UltBook => "ULTBOOK",
+        Cherry => "CHERRY",
+        Strawberry => "STRAWBERRY",
+        Brawl => "BRAWL",
+        Swiftplay => "SWIFTPLAY",
+        TutorialModule1 => "TUTORIAL_MODULE_1",
+        TutorialModule2 => "TUTORIAL_MODULE_2",
+        TutorialModule3 => "TUTORIAL_MODULE_3",
+        PracticeTool => "PRACTICETOOL"
+    }
+}
+
+impl GameMode {
+    pub fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            GameMode::Classic => "Classic Summoner's Rift and Twisted Treeline games",
+            GameMode::Odin => "Dominion/Crystal Scar games",
+            GameMode::Aram => "ARAM games",
+            GameMode::Tutorial => "Tutorial games",
+            GameMode::Urf => "URF games",
+            GameMode::DoomBotsTeemo => "Doom Bot games",
+            GameMode::OneForAll => "One for All games",
+            GameMode::Ascension => "Ascension games",
+            GameMode::FirstBlood => "Snowdown Showdown games",
+            GameMode::KingPoro => "Legend of the Poro King games",
+            GameMode::Siege => "Nexus Siege games",
+            GameMode::Assassinate => "Blood Hunt Assassin games",
+            GameMode::Arsr => "All Random Summoner's Rift games",
+            GameMode::DarkStar => "Dark Star: Singularity games",
+            GameMode::StarGuardian => "Star Guardian Invasion games",
+            GameMode::Project => "PROJECT: Hunters games",
+            GameMode::GameModeX => "Nexus Blitz games",
+            GameMode::Odyssey => "Odyssey: Extraction games",
+            GameMode::NexusBlitz => "Nexus Blitz games",
+            GameMode::UltBook => "Ultimate Spellbook games",
+            GameMode::Cherry => "Arena games",
+            GameMode::Strawberry => "Swarm games",
+            GameMode::Brawl => "Brawl games",
+            GameMode::Swiftplay => "Swiftplay games",
+            GameMode::TutorialModule1 => "Tutorial 1 games",
+            GameMode::TutorialModule2 => "Tutorial 2 games",
+            GameMode::TutorialModule3 => "Tutorial 3 games",
+            GameMode::PracticeTool => "Practice Tool games",
+            GameMode::Unknown(_) => return None
+        };
+        Some(description)
+    }
+}
+
+string_enum! {
+    pub enum GameType {
+        CustomGame => "CUSTOM_GAME",
+        TutorialGame => "TUTORIAL_GAME",
+        MatchedGame => "MATCHED_GAME"
+    }
+}
+
+impl GameType {
+    pub fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            GameType::CustomGame => "Custom games",
+            GameType::TutorialGame => "Tutorial games",
+            GameType::MatchedGame => "All other games",
+            GameType::Unknown(_) => return None
+        };
+        Some(description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_metadata() {
+        let queue = Queue::from(420);
+        assert_eq!(queue, Queue::RankedSolo5x5);
+        assert_eq!(queue.id(), 420);
+        assert_eq!(queue.map(), Some(Map::SummonersRift));
+        assert_eq!(queue.description(), Some("5v5 Ranked Solo games"));
+        assert!(!queue.is_deprecated());
+
+        assert!(Queue::RankedDynamic5x5.is_deprecated());
+        assert_eq!(Queue::Custom.map(), None);
+        assert_eq!(Queue::Aram.map().and_then(|map| map.name()), Some("Howling Abyss"));
+        assert_eq!(Queue::from(1830).map(), Some(Map::Swarm));
+        assert_eq!(Queue::from(2300).map().and_then(|map| map.name()), Some("The Bandlewood"));
+        assert!(Queue::CoopVsAiIntro.is_deprecated() && !Queue::CoopVsAiIntroBot.is_deprecated());
+    }
+
+    #[test]
+    fn unknown_ids_round_trip() {
+        let queue: Queue = serde_json::from_str("4242").expect("Failed to deserialize");
+        assert_eq!(queue, Queue::Unknown(4242));
+        assert_eq!(queue.description(), None);
+        assert_eq!(serde_json::to_string(&queue).expect("Failed to serialize"), "4242");
+
+        let game_mode: GameMode = serde_json::from_str(r#""NEWMODE""#).expect("Failed to deserialize");
+        assert_eq!(game_mode, GameMode::Unknown("NEWMODE".to_string()));
+        assert_eq!(serde_json::to_string(&game_mode).expect("Failed to serialize"), r#""NEWMODE""#);
+        assert_eq!(GameMode::from("CHERRY").description(), Some("Arena games"));
+        assert_eq!(GameMode::from("BRAWL"), GameMode::Brawl);
+        assert_eq!(GameMode::from("STRAWBERRY").description(), Some("Swarm games"));
+    }
+}
@@ -0,0 +1,121 @@
+pub mod constants;
+use self::constants::{ChallengeListDto, CherryAugmentDto, PerkDto, TftDataDto};
+use serde::de::DeserializeOwned;
+use std::error::Error;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const CDRAGON_BASE_URL: &str = "https://raw.communitydragon.org";
+
+const GAME_DATA: &str = "plugins/rcp-be-lol-game-data/global";
+
+/// Client for Community Dragon, which serves game data Data Dragon leaves out, such as Arena
+/// augments, challenges and TFT sets.
+///
+/// `version` is either "latest", "pbe" or a patch like "13.1". Game data `locale`s are
+/// lowercase, with "default" meaning en_US.
+pub struct CommunityDragon {
+    client: reqwest::Client,
+    base_url: String
+}
+
+impl CommunityDragon {
+    pub fn new() -> std::result::Result<Self, reqwest::Error> {
+        let client = reqwest::ClientBuilder::new()
+            .build()?;
+
+        Ok(CommunityDragon {
+            client,
+            base_url: CDRAGON_BASE_URL.to_string()
+        })
+    }
+
+    /// Fetches from `base_url` instead of Community Dragon, e.g. a local mirror with the same
+    /// layout.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub async fn cherry_augments(&self, version: &str, locale: &str) -> Result<Vec<CherryAugmentDto>> {
+        self.get(&format!("/{version}/{GAME_DATA}/{locale}/v1/cherry-augments.json")).await
+    }
+
+    pub async fn challenges(&self, version: &str, locale: &str) -> Result<ChallengeListDto> {
+        self.get(&format!("/{version}/{GAME_DATA}/{locale}/v1/challenges.json")).await
+    }
+
+    /// Runes and stat shards with their full descriptions.
+    pub async fn perks(&self, version: &str, locale: &str) -> Result<Vec<PerkDto>> {
+        self.get(&format!("/{version}/{GAME_DATA}/{locale}/v1/perks.json")).await
+    }
+
+    /// Items and sets of Teamfight Tactics. Unlike game data, the locale here is e.g. "en_us".
+    pub async fn tft(&self, version: &str, locale: &str) -> Result<TftDataDto> {
+        self.get(&format!("/{version}/cdragon/tft/{locale}.json")).await
+    }
+
+    /// URL of an asset referenced by game data, such as `PerkDto::icon_path`.
+    pub fn asset_url(&self, version: &str, path: &str) -> String {
+        let path = path
+            .trim_start_matches("/lol-game-data/assets/")
+            .to_lowercase();
+        format!("{}/{version}/{GAME_DATA}/default/{path}", self.base_url)
+    }
+
+    async fn get<T>(&self, path: &str) -> Result<T>
+    where
+        T: DeserializeOwned
+    {
+        let resp = self
+            .client
+            .get(format!("{}{path}", self.base_url))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::constants::AugmentRarity;
+    use crate::test_utils::MockServer;
+
+    #[tokio::test]
+    async fn game_data_from_mirror() {
+        let server = MockServer::serve_dir("tests/fixtures/cdragon").await;
+        let cdragon = CommunityDragon::new().expect("Failed to create client").with_base_url(server.url());
+
+        let augments = cdragon.cherry_augments("latest", "default").await.expect("Failed to get augments");
+        assert_eq!(augments[2].name, "Master of Duality");
+        assert_eq!(augments[2].rarity, AugmentRarity::Prismatic);
+
+        let challenges = cdragon.challenges("latest", "default").await.expect("Failed to get challenges");
+        assert_eq!(challenges.challenges[&202303].name, "Dragonslayer");
+        assert_eq!(challenges.challenges[&202303].thresholds["GOLD"].value, 10.0);
+
+        let perks = cdragon.perks("latest", "default").await.expect("Failed to get perks");
+        let press_the_attack = perks.iter().find(|perk| perk.id == 8005).expect("Missing perk");
+        assert_eq!(
+            cdragon.asset_url("latest", &press_the_attack.icon_path),
+            format!("{}/latest/{GAME_DATA}/default/v1/perk-images/styles/precision/presstheattack/presstheattack.png", server.url())
+        );
+
+        assert!(cdragon.perks("13.1", "default").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn tft_sets() {
+        let server = MockServer::serve_dir("tests/fixtures/cdragon").await;
+        let cdragon = CommunityDragon::new().expect("Failed to create client").with_base_url(server.url());
+
+        let tft = cdragon.tft("latest", "en_us").await.expect("Failed to get TFT data");
+        let set = &tft.set_data[0];
+        assert_eq!(set.number, 8);
+        assert_eq!(set.champions[1].traits, ["Renegade", "Heart"]);
+        assert_eq!(tft.items[1].composition.len(), 2);
+    }
+}
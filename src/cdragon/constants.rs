@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+string_enum! {
+    pub enum AugmentRarity {
+        Silver => "kSilver",
+        Gold => "kGold",
+        Prismatic => "kPrismatic"
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CherryAugmentDto {
+    pub id: i32,
+    #[serde(rename = "nameTRA")]
+    pub name: String,
+    pub augment_small_icon_path: String,
+    pub rarity: AugmentRarity
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChallengeListDto {
+    pub challenges: BTreeMap<i64, ChallengeDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeDto {
+    pub id: i64,
+    pub name: String,
+    pub description: String,
+    pub description_short: String,
+    pub icon_path: String,
+    pub category: String,
+    pub is_capstone: bool,
+    pub thresholds: BTreeMap<String, ChallengeThresholdDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChallengeThresholdDto {
+    pub value: f64
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PerkDto {
+    pub id: i32,
+    pub name: String,
+    pub major_change_patch_version: String,
+    pub tooltip: String,
+    pub short_desc: String,
+    pub long_desc: String,
+    pub recommendation_descriptor: String,
+    pub icon_path: String,
+    pub end_of_game_stat_descs: Vec<String>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TftDataDto {
+    pub items: Vec<TftItemDto>,
+    pub set_data: Vec<TftSetDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TftItemDto {
+    pub api_name: String,
+    pub name: Option<String>,
+    pub desc: Option<String>,
+    pub icon: String,
+    pub composition: Vec<String>,
+    pub effects: BTreeMap<String, Value>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TftSetDto {
+    pub number: i32,
+    pub mutator: String,
+    pub name: String,
+    pub champions: Vec<TftChampionDto>,
+    pub traits: Vec<TftTraitDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TftChampionDto {
+    pub api_name: String,
+    pub character_name: Option<String>,
+    pub name: String,
+    pub cost: i32,
+    pub traits: Vec<String>,
+    pub icon: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TftTraitDto {
+    pub api_name: String,
+    pub name: String,
+    pub desc: String,
+    pub icon: String,
+    pub effects: Vec<TftTraitEffectDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TftTraitEffectDto {
+    pub min_units: i32,
+    pub max_units: i32,
+    pub style: i32,
+    pub variables: BTreeMap<String, Value>
+}
@@ -0,0 +1,476 @@
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::HashMap, fmt, sync::{OnceLock, RwLock}};
+
+/// A champion, identified by its numeric key. Names are looked up in a table of the champions
+/// known when this crate was published, which [`Champion::register`] extends with newer ones.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct Champion(i64);
+
+/// Built-in names are borrowed, registered ones owned, so renaming a champion frees its old names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Names {
+    name: Cow<'static, str>,
+    display_name: Cow<'static, str>
+}
+
+const BUILT_IN: &[(i64, &str, &str)] = &[
+    (1, "Annie", "Annie"),
+    (2, "Olaf", "Olaf"),
+    (3, "Galio", "Galio"),
+    (4, "TwistedFate", "Twisted Fate"),
+    (5, "XinZhao", "Xin Zhao"),
+    (6, "Urgot", "Urgot"),
+    (7, "Leblanc", "LeBlanc"),
+    (8, "Vladimir", "Vladimir"),
+    (9, "Fiddlesticks", "Fiddlesticks"),
+    (10, "Kayle", "Kayle"),
+    (11, "MasterYi", "Master Yi"),
+    (12, "Alistar", "Alistar"),
+    (13, "Ryze", "Ryze"),
+    (14, "Sion", "Sion"),
+    (15, "Sivir", "Sivir"),
+    (16, "Soraka", "Soraka"),
+    (17, "Teemo", "Teemo"),
+    (18, "Tristana", "Tristana"),
+    (19, "Warwick", "Warwick"),
+    (20, "Nunu", "Nunu & Willump"),
+    (21, "MissFortune", "Miss Fortune"),
+    (22, "Ashe", "Ashe"),
+    (23, "Tryndamere", "Tryndamere"),
+    (24, "Jax", "Jax"),
+    (25, "Morgana", "Morgana"),
+    (26, "Zilean", "Zilean"),
+    (27, "Singed", "Singed"),
+    (28, "Evelynn", "Evelynn"),
+    (29, "Twitch", "Twitch"),
+    (30, "Karthus", "Karthus"),
+    (31, "Chogath", "Cho'Gath"),
+    (32, "Amumu", "Amumu"),
+    (33, "Rammus", "Rammus"),
+    (34, "Anivia", "Anivia"),
+    (35, "Shaco", "Shaco"),
+    (36, "DrMundo", "Dr. Mundo"),
+    (37, "Sona", "Sona"),
+    (38, "Kassadin", "Kassadin"),
+    (39, "Irelia", "Irelia"),
+    (40, "Janna", "Janna"),
+    (41, "Gangplank", "Gangplank"),
+    (42, "Corki", "Corki"),
+    (43, "Karma", "Karma"),
+    (44, "Taric", "Taric"),
+    (45, "Veigar", "Veigar"),
+    (48, "Trundle", "Trundle"),
+    (50, "Swain", "Swain"),
+    (51, "Caitlyn", "Caitlyn"),
+    (53, "Blitzcrank", "Blitzcrank"),
+    (54, "Malphite", "Malphite"),
+    (55, "Katarina", "Katarina"),
+    (56, "Nocturne", "Nocturne"),
+    (57, "Maokai", "Maokai"),
+    (58, "Renekton", "Renekton"),
+    (59, "JarvanIV", "Jarvan IV"),
+    (60, "Elise", "Elise"),
+    (61, "Orianna", "Orianna"),
+    (62, "MonkeyKing", "Wukong"),
+    (63, "Brand", "Brand"),
+    (64, "LeeSin", "Lee Sin"),
+    (67, "Vayne", "Vayne"),
+    (68, "Rumble", "Rumble"),
+    (69, "Cassiopeia", "Cassiopeia"),
+    (72, "Skarner", "Skarner"),
+    (74, "Heimerdinger", "Heimerdinger"),
+    (75, "Nasus", "Nasus"),
+    (76, "Nidalee", "Nidalee"),
+    (77, "Udyr", "Udyr"),
+    (78, "Poppy", "Poppy"),
+    (79, "Gragas", "Gragas"),
+    (80, "Pantheon", "Pantheon"),
+    (81, "Ezreal", "Ezreal"),
+    (82, "Mordekaiser", "Mordekaiser"),
+    (83, "Yorick", "Yorick"),
+    (84, "Akali", "Akali"),
+    (85, "Kennen", "Kennen"),
+    (86, "Garen", "Garen"),
+    (89, "Leona", "Leona"),
+    (90, "Malzahar", "Malzahar"),
+    (91, "Talon", "Talon"),
+    (92, "Riven", "Riven"),
+    (96, "KogMaw", "Kog'Maw"),
+    (98, "Shen", "Shen"),
+    (99, "Lux", "Lux"),
+    (101, "Xerath", "Xerath"),
+    (102, "Shyvana", "Shyvana"),
+    (103, "Ahri", "Ahri"),
+    (104, "Graves", "Graves"),
+    (105, "Fizz", "Fizz"),
+    (106, "Volibear", "Volibear"),
+    (107, "Rengar", "Rengar"),
+    (110, "Varus", "Varus"),
+    (111, "Nautilus", "Nautilus"),
+    (112, "Viktor", "Viktor"),
+    (113, "Sejuani", "Sejuani"),
+    (114, "Fiora", "Fiora"),
+    (115, "Ziggs", "Ziggs"),
+    (117, "Lulu", "Lulu"),
+    (119, "Draven", "Draven"),
+    (120, "Hecarim", "Hecarim"),
+    (121, "Khazix", "Kha'Zix"),
+    (122, "Darius", "Darius"),
+    (126, "Jayce", "Jayce"),
+    (127, "Lissandra", "Lissandra"),
+    (131, "Diana", "Diana"),
+    (133, "Quinn", "Quinn"),
+    (134, "Syndra", "Syndra"),
+    (136, "AurelionSol", "Aurelion Sol"),
+    (141, "Kayn", "Kayn"),
+    (142, "Zoe", "Zoe"),
+    (143, "Zyra", "Zyra"),
+    (145, "Kaisa", "Kai'Sa"),
+    (147, "Seraphine", "Seraphine"),
+    (150, "Gnar", "Gnar"),
+    (154, "Zac", "Zac"),
+    (157, "Yasuo", "Yasuo"),
+    (161, "Velkoz", "Vel'Koz"),
+    (163, "Taliyah", "Taliyah"),
+    (164, "Camille", "Camille"),
+    (166, "Akshan", "Akshan"),
+    (200, "Belveth", "Bel'Veth"),
+    (201, "Braum", "Braum"),
+    (202, "Jhin", "Jhin"),
+    (203, "Kindred", "Kindred"),
+    (221, "Zeri", "Zeri"),
+    (222, "Jinx", "Jinx"),
+    (223, "TahmKench", "Tahm Kench"),
+    (233, "Briar", "Briar"),
+    (234, "Viego", "Viego"),
+    (235, "Senna", "Senna"),
+    (236, "Lucian", "Lucian"),
+    (238, "Zed", "Zed"),
+    (240, "Kled", "Kled"),
+    (245, "Ekko", "Ekko"),
+    (246, "Qiyana", "Qiyana"),
+    (254, "Vi", "Vi"),
+    (266, "Aatrox", "Aatrox"),
+    (267, "Nami", "Nami"),
+    (268, "Azir", "Azir"),
+    (350, "Yuumi", "Yuumi"),
+    (360, "Samira", "Samira"),
+    (412, "Thresh", "Thresh"),
+    (420, "Illaoi", "Illaoi"),
+    (421, "RekSai", "Rek'Sai"),
+    (427, "Ivern", "Ivern"),
+    (429, "Kalista", "Kalista"),
+    (432, "Bard", "Bard"),
+    (497, "Rakan", "Rakan"),
+    (498, "Xayah", "Xayah"),
+    (516, "Ornn", "Ornn"),
+    (517, "Sylas", "Sylas"),
+    (518, "Neeko", "Neeko"),
+    (523, "Aphelios", "Aphelios"),
+    (526, "Rell", "Rell"),
+    (555, "Pyke", "Pyke"),
+    (711, "Vex", "Vex"),
+    (777, "Yone", "Yone"),
+    (799, "Ambessa", "Ambessa"),
+    (800, "Mel", "Mel"),
+    (875, "Sett", "Sett"),
+    (876, "Lillia", "Lillia"),
+    (887, "Gwen", "Gwen"),
+    (888, "Renata", "Renata Glasc"),
+    (893, "Aurora", "Aurora"),
+    (895, "Nilah", "Nilah"),
+    (897, "KSante", "K'Sante"),
+    (901, "Smolder", "Smolder"),
+    (902, "Milio", "Milio"),
+    (910, "Hwei", "Hwei"),
+    (950, "Naafiri", "Naafiri")
+];
+
+fn registry() -> &'static RwLock<HashMap<i64, Names>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<i64, Names>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let champions = BUILT_IN
+            .iter()
+            .map(|&(key, name, display_name)| (key, Names { name: name.into(), display_name: display_name.into() }))
+            .collect();
+        RwLock::new(champions)
+    })
+}
+
+impl Champion {
+    /// Sent as the champion of a ban slot that was left empty.
+    pub const NONE: Champion = Champion(-1);
+    pub const ANNIE: Champion = Champion(1);
+    pub const OLAF: Champion = Champion(2);
+    pub const GALIO: Champion = Champion(3);
+    pub const TWISTED_FATE: Champion = Champion(4);
+    pub const XIN_ZHAO: Champion = Champion(5);
+    pub const URGOT: Champion = Champion(6);
+    pub const LEBLANC: Champion = Champion(7);
+    pub const VLADIMIR: Champion = Champion(8);
+    pub const FIDDLESTICKS: Champion = Champion(9);
+    pub const KAYLE: Champion = Champion(10);
+    pub const MASTER_YI: Champion = Champion(11);
+    pub const ALISTAR: Champion = Champion(12);
+    pub const RYZE: Champion = Champion(13);
+    pub const SION: Champion = Champion(14);
+    pub const SIVIR: Champion = Champion(15);
+    pub const SORAKA: Champion = Champion(16);
+    pub const TEEMO: Champion = Champion(17);
+    pub const TRISTANA: Champion = Champion(18);
+    pub const WARWICK: Champion = Champion(19);
+    pub const NUNU: Champion = Champion(20);
+    pub const MISS_FORTUNE: Champion = Champion(21);
+    pub const ASHE: Champion = Champion(22);
+    pub const TRYNDAMERE: Champion = Champion(23);
+    pub const JAX: Champion = Champion(24);
+    pub const MORGANA: Champion = Champion(25);
+    pub const ZILEAN: Champion = Champion(26);
+    pub const SINGED: Champion = Champion(27);
+    pub const EVELYNN: Champion = Champion(28);
+    pub const TWITCH: Champion = Champion(29);
+    pub const KARTHUS: Champion = Champion(30);
+    pub const CHOGATH: Champion = Champion(31);
+    pub const AMUMU: Champion = Champion(32);
+    pub const RAMMUS: Champion = Champion(33);
+    pub const ANIVIA: Champion = Champion(34);
+    pub const SHACO: Champion = Champion(35);
+    pub const DR_MUNDO: Champion = Champion(36);
+    pub const SONA: Champion = Champion(37);
+    pub const KASSADIN: Champion = Champion(38);
+    pub const IRELIA: Champion = Champion(39);
+    pub const JANNA: Champion = Champion(40);
+    pub const GANGPLANK: Champion = Champion(41);
+    pub const CORKI: Champion = Champion(42);
+    pub const KARMA: Champion = Champion(43);
+    pub const TARIC: Champion = Champion(44);
+    pub const VEIGAR: Champion = Champion(45);
+    pub const TRUNDLE: Champion = Champion(48);
+    pub const SWAIN: Champion = Champion(50);
+    pub const CAITLYN: Champion = Champion(51);
+    pub const BLITZCRANK: Champion = Champion(53);
+    pub const MALPHITE: Champion = Champion(54);
+    pub const KATARINA: Champion = Champion(55);
+    pub const NOCTURNE: Champion = Champion(56);
+    pub const MAOKAI: Champion = Champion(57);
+    pub const RENEKTON: Champion = Champion(58);
+    pub const JARVAN_IV: Champion = Champion(59);
+    pub const ELISE: Champion = Champion(60);
+    pub const ORIANNA: Champion = Champion(61);
+    pub const MONKEY_KING: Champion = Champion(62);
+    pub const BRAND: Champion = Champion(63);
+    pub const LEE_SIN: Champion = Champion(64);
+    pub const VAYNE: Champion = Champion(67);
+    pub const RUMBLE: Champion = Champion(68);
+    pub const CASSIOPEIA: Champion = Champion(69);
+    pub const SKARNER: Champion = Champion(72);
+    pub const HEIMERDINGER: Champion = Champion(74);
+    pub const NASUS: Champion = Champion(75);
+    pub const NIDALEE: Champion = Champion(76);
+    pub const UDYR: Champion = Champion(77);
+    pub const POPPY: Champion = Champion(78);
+    pub const GRAGAS: Champion = Champion(79);
+    pub const PANTHEON: Champion = Champion(80);
+    pub const EZREAL: Champion = Champion(81);
+    pub const MORDEKAISER: Champion = Champion(82);
+    pub const YORICK: Champion = Champion(83);
+    pub const AKALI: Champion = Champion(84);
+    pub const KENNEN: Champion = Champion(85);
+    pub const GAREN: Champion = Champion(86);
+    pub const LEONA: Champion = Champion(89);
+    pub const MALZAHAR: Champion = Champion(90);
+    pub const TALON: Champion = Champion(91);
+    pub const RIVEN: Champion = Champion(92);
+    pub const KOG_MAW: Champion = Champion(96);
+    pub const SHEN: Champion = Champion(98);
+    pub const LUX: Champion = Champion(99);
+    pub const XERATH: Champion = Champion(101);
+    pub const SHYVANA: Champion = Champion(102);
+    pub const AHRI: Champion = Champion(103);
+    pub const GRAVES: Champion = Champion(104);
+    pub const FIZZ: Champion = Champion(105);
+    pub const VOLIBEAR: Champion = Champion(106);
+    pub const RENGAR: Champion = Champion(107);
+    pub const VARUS: Champion = Champion(110);
+    pub const NAUTILUS: Champion = Champion(111);
+    pub const VIKTOR: Champion = Champion(112);
+    pub const SEJUANI: Champion = Champion(113);
+    pub const FIORA: Champion = Champion(114);
+    pub const ZIGGS: Champion = Champion(115);
+    pub const LULU: Champion = Champion(117);
+    pub const DRAVEN: Champion = Champion(119);
+    pub const HECARIM: Champion = Champion(120);
+    pub const KHAZIX: Champion = Champion(121);
+    pub const DARIUS: Champion = Champion(122);
+    pub const JAYCE: Champion = Champion(126);
+    pub const LISSANDRA: Champion = Champion(127);
+    pub const DIANA: Champion = Champion(131);
+    pub const QUINN: Champion = Champion(133);
+    pub const SYNDRA: Champion = Champion(134);
+    pub const AURELION_SOL: Champion = Champion(136);
+    pub const KAYN: Champion = Champion(141);
+    pub const ZOE: Champion = Champion(142);
+    pub const ZYRA: Champion = Champion(143);
+    pub const KAISA: Champion = Champion(145);
+    pub const SERAPHINE: Champion = Champion(147);
+    pub const GNAR: Champion = Champion(150);
+    pub const ZAC: Champion = Champion(154);
+    pub const YASUO: Champion = Champion(157);
+    pub const VELKOZ: Champion = Champion(161);
+    pub const TALIYAH: Champion = Champion(163);
+    pub const CAMILLE: Champion = Champion(164);
+    pub const AKSHAN: Champion = Champion(166);
+    pub const BELVETH: Champion = Champion(200);
+    pub const BRAUM: Champion = Champion(201);
+    pub const JHIN: Champion = Champion(202);
+    pub const KINDRED: Champion = Champion(203);
+    pub const ZERI: Champion = Champion(221);
+    pub const JINX: Champion = Champion(222);
+    pub const TAHM_KENCH: Champion = Champion(223);
+    pub const BRIAR: Champion = Champion(233);
+    pub const VIEGO: Champion = Champion(234);
+    pub const SENNA: Champion = Champion(235);
+    pub const LUCIAN: Champion = Champion(236);
+    pub const ZED: Champion = Champion(238);
+    pub const KLED: Champion = Champion(240);
+    pub const EKKO: Champion = Champion(245);
+    pub const QIYANA: Champion = Champion(246);
+    pub const VI: Champion = Champion(254);
+    pub const AATROX: Champion = Champion(266);
+    pub const NAMI: Champion = Champion(267);
+    pub const AZIR: Champion = Champion(268);
+    pub const YUUMI: Champion = Champion(350);
+    pub const SAMIRA: Champion = Champion(360);
+    pub const THRESH: Champion = Champion(412);
+    pub const ILLAOI: Champion = Champion(420);
+    pub const REK_SAI: Champion = Champion(421);
+    pub const IVERN: Champion = Champion(427);
+    pub const KALISTA: Champion = Champion(429);
+    pub const BARD: Champion = Champion(432);
+    pub const RAKAN: Champion = Champion(497);
+    pub const XAYAH: Champion = Champion(498);
+    pub const ORNN: Champion = Champion(516);
+    pub const SYLAS: Champion = Champion(517);
+    pub const NEEKO: Champion = Champion(518);
+    pub const APHELIOS: Champion = Champion(523);
+    pub const RELL: Champion = Champion(526);
+    pub const PYKE: Champion = Champion(555);
+    pub const VEX: Champion = Champion(711);
+    pub const YONE: Champion = Champion(777);
+    pub const AMBESSA: Champion = Champion(799);
+    pub const MEL: Champion = Champion(800);
+    pub const SETT: Champion = Champion(875);
+    pub const LILLIA: Champion = Champion(876);
+    pub const GWEN: Champion = Champion(887);
+    pub const RENATA: Champion = Champion(888);
+    pub const AURORA: Champion = Champion(893);
+    pub const NILAH: Champion = Champion(895);
+    pub const K_SANTE: Champion = Champion(897);
+    pub const SMOLDER: Champion = Champion(901);
+    pub const MILIO: Champion = Champion(902);
+    pub const HWEI: Champion = Champion(910);
+    pub const NAAFIRI: Champion = Champion(950);
+
+    pub const fn from_key(key: i64) -> Self {
+        Champion(key)
+    }
+
+    pub fn key(&self) -> i64 {
+        self.0
+    }
+
+    /// Looks a champion up by its internal name ("MonkeyKing") or display name ("Wukong"),
+    /// ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        registry()
+            .read()
+            .unwrap()
+            .iter()
+            .find(|(_, names)| names.name.eq_ignore_ascii_case(name) || names.display_name.eq_ignore_ascii_case(name))
+            .map(|(key, _)| Champion(*key))
+    }
+
+    /// Internal name, as used by Data Dragon and `ParticipantDto::champion_name`.
+    pub fn name(&self) -> Option<Cow<'static, str>> {
+        self.names().map(|names| names.name)
+    }
+
+    pub fn display_name(&self) -> Option<Cow<'static, str>> {
+        self.names().map(|names| names.display_name)
+    }
+
+    fn names(&self) -> Option<Names> {
+        registry().read().unwrap().get(&self.0).cloned()
+    }
+
+    /// Adds a champion released after this crate was published, or renames a known one.
+    pub fn register(key: i64, name: &str, display_name: &str) {
+        let mut registry = registry().write().unwrap();
+        let unchanged = registry
+            .get(&key)
+            .is_some_and(|names| names.name == name && names.display_name == display_name);
+
+        if !unchanged {
+            let names = Names {
+                name: Cow::Owned(name.to_string()),
+                display_name: Cow::Owned(display_name.to_string())
+            };
+            registry.insert(key, names);
+        }
+    }
+
+    /// Every champion with known names, ordered by key.
+    pub fn all() -> Vec<Champion> {
+        let mut champions: Vec<_> = registry().read().unwrap().keys().map(|key| Champion(*key)).collect();
+        champions.sort();
+        champions
+    }
+}
+
+impl From<i64> for Champion {
+    fn from(key: i64) -> Self {
+        Champion(key)
+    }
+}
+
+impl From<Champion> for i64 {
+    fn from(champion: Champion) -> Self {
+        champion.0
+    }
+}
+
+impl fmt::Display for Champion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.display_name() {
+            Some(display_name) => f.write_str(&display_name),
+            None => write!(f, "{}", self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookups() {
+        assert_eq!(Champion::from_name("MonkeyKing"), Some(Champion::MONKEY_KING));
+        assert_eq!(Champion::from_name("wukong"), Some(Champion::MONKEY_KING));
+        assert_eq!(Champion::MONKEY_KING.key(), 62);
+        assert_eq!(Champion::MONKEY_KING.name().as_deref(), Some("MonkeyKing"));
+        assert_eq!(Champion::from_key(518).to_string(), "Neeko");
+        assert_eq!(Champion::NONE.name(), None);
+    }
+
+    #[test]
+    fn register_new_champion() {
+        let champion = Champion::from_key(9001);
+        assert_eq!(champion.to_string(), "9001");
+
+        Champion::register(9001, "Newchamp", "New Champ");
+        assert_eq!(champion.display_name().as_deref(), Some("New Champ"));
+        assert_eq!(Champion::from_name("Newchamp"), Some(champion));
+        assert!(Champion::all().contains(&champion));
+    }
+}
@@ -1,69 +1,401 @@
 pub mod constants;
+mod rate_limit;
 use crate::endpoints::constants::Error as ApiError;
-use self::constants::Region;
-use std::{collections::HashMap, error::Error};
-use serde::de::DeserializeOwned;
+use crate::lenient::{self, SchemaDrift};
+#[cfg(feature = "sqlite")]
+use crate::store::MatchStore;
+use self::constants::{EndpointGroup, RateLimit, Region};
+use self::rate_limit::RateLimiter;
+use std::{collections::HashMap, error::Error, future::Future, pin::Pin, sync::{Arc, RwLock}};
+use serde::{de::DeserializeOwned, Serialize};
 
 // type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 type Result<T> = std::result::Result<T, reqwest::Error>;
 
+pub type ApiKeyFuture<'a> = Pin<Box<dyn Future<Output = Option<String>> + Send + 'a>>;
+
+/// Supplies a replacement API key once Riot rejects the current one with 401 or 403,
+/// e.g. by re-reading a rotated development key from disk. The lookup is awaited by the
+/// failed request, so blocking work belongs in `tokio::fs` or `spawn_blocking`.
+pub trait ApiKeyProvider: Send + Sync {
+    /// Called with the endpoint group of the failed request (`None` for [`Client::request`])
+    /// and the rejected key. Returning `None` keeps the current key and surfaces the error.
+    fn refresh_api_key<'a>(&'a self, group: Option<EndpointGroup>, rejected_key: &'a str) -> ApiKeyFuture<'a>;
+}
+
+impl<F, Fut> ApiKeyProvider for F
+where
+    F: Fn(Option<EndpointGroup>, String) -> Fut + Send + Sync,
+    Fut: Future<Output = Option<String>> + Send + 'static
+{
+    fn refresh_api_key<'a>(&'a self, group: Option<EndpointGroup>, rejected_key: &'a str) -> ApiKeyFuture<'a> {
+        Box::pin(self(group, rejected_key.to_string()))
+    }
+}
+
+type DriftHandler = dyn Fn(&str, &SchemaDrift) + Send + Sync;
+#[cfg(feature = "sqlite")]
+type StoreErrorHandler = dyn Fn(&str, &dyn Error) + Send + Sync;
+
+struct ApiKey {
+    key: RwLock<String>,
+    rate_limiter: RateLimiter
+}
+
+impl ApiKey {
+    fn new(key: String) -> Arc<Self> {
+        Arc::new(ApiKey {
+            key: RwLock::new(key),
+            rate_limiter: RateLimiter::default()
+        })
+    }
+
+    fn get(&self) -> String {
+        self.key.read().unwrap().clone()
+    }
+
+    fn set(&self, key: String) {
+        *self.key.write().unwrap() = key;
+    }
+}
+
 pub struct Client {
     client: reqwest::Client,
-    region: Region
+    default_key: Arc<ApiKey>,
+    keys: RwLock<HashMap<EndpointGroup, Arc<ApiKey>>>,
+    key_provider: Option<Box<dyn ApiKeyProvider>>,
+    drift_handler: Option<Box<DriftHandler>>,
+    region: Region,
+    base_url: Option<String>,
+    #[cfg(feature = "sqlite")]
+    store: Option<Arc<MatchStore>>,
+    #[cfg(feature = "sqlite")]
+    store_error_handler: Option<Box<StoreErrorHandler>>
 }
 
 impl Client {
     pub fn new(api_key: String, region: Region) -> Result<Self> {
-        let mut headers = reqwest::header::HeaderMap::new();
-
-        headers.insert("X-Riot-Token", api_key.parse().unwrap());
-
         let client = reqwest::ClientBuilder::new()
-            .default_headers(headers)
             .build()?;
 
         Ok(Client {
             client,
-            region
+            default_key: ApiKey::new(api_key),
+            keys: RwLock::new(HashMap::new()),
+            key_provider: None,
+            drift_handler: None,
+            region,
+            base_url: None,
+            #[cfg(feature = "sqlite")]
+            store: None,
+            #[cfg(feature = "sqlite")]
+            store_error_handler: None
+        })
+    }
+
+    /// Registers an additional API key used for the given endpoint groups instead of the
+    /// default key. Every key keeps its own rate-limit accounting, while all keys share the
+    /// same connection pool. The given groups share the key until
+    /// [`set_group_api_key`](Self::set_group_api_key) gives one of them its own.
+    pub fn with_api_key(mut self, api_key: String, groups: &[EndpointGroup]) -> Self {
+        let api_key = ApiKey::new(api_key);
+        for group in groups {
+            self.keys.get_mut().unwrap().insert(*group, api_key.clone());
+        }
+        self
+    }
+
+    pub fn with_api_key_provider(mut self, provider: impl ApiKeyProvider + 'static) -> Self {
+        self.key_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Deserializes responses leniently: missing fields become defaults and unknown fields are
+    /// collected instead of failing the request. `on_drift` is called with the endpoint path
+    /// whenever a response does not match its DTO exactly. The returned DTOs do not keep unknown
+    /// fields, they are only reported to `on_drift` in [`SchemaDrift::unknown_fields`].
+    pub fn with_lenient_deserialization(
+        mut self,
+        on_drift: impl Fn(&str, &SchemaDrift) + Send + Sync + 'static
+    ) -> Self {
+        self.drift_handler = Some(Box::new(on_drift));
+        self
+    }
+
+    /// Replaces the default API key. Takes effect for all requests sent afterwards, including
+    /// those made through other references to this client.
+    pub fn set_api_key(&self, api_key: String) {
+        self.default_key.set(api_key);
+    }
+
+    /// Gives `group` a key of its own with fresh rate-limit accounting. The default key and
+    /// other groups, including those that shared a key with `group`, are left untouched.
+    pub fn set_group_api_key(&self, group: EndpointGroup, api_key: String) {
+        self.keys.write().unwrap().insert(group, ApiKey::new(api_key));
+    }
+
+    /// Sends every request to `base_url` instead of `https://{region}.api.riotgames.com`,
+    /// e.g. to point the client at a proxy or a local stand-in server.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Looks up matches and timelines in `store` before requesting them, and saves the ones
+    /// that had to be requested. Entries that cannot be read are requested again and
+    /// overwritten.
+    #[cfg(feature = "sqlite")]
+    pub fn with_store(mut self, store: Arc<MatchStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Calls `on_error` with the match id whenever reading from or saving to the store fails.
+    /// Store errors never fail a request.
+    #[cfg(feature = "sqlite")]
+    pub fn with_store_error_handler(mut self, on_error: impl Fn(&str, &dyn Error) + Send + Sync + 'static) -> Self {
+        self.store_error_handler = Some(Box::new(on_error));
+        self
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub fn store(&self) -> Option<&Arc<MatchStore>> {
+        self.store.as_ref()
+    }
+
+    /// Reads `match_id` from the store, treating a failed read as a miss.
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn load_stored<T>(
+        &self,
+        match_id: &str,
+        load: impl FnOnce(&MatchStore) -> std::result::Result<Option<T>, Box<dyn Error>>
+    ) -> Option<T> {
+        let store = self.store.as_ref()?;
+        load(store).unwrap_or_else(|error| {
+            self.report_store_error(match_id, &*error);
+            None
         })
     }
 
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn save_fetched(&self, match_id: &str, save: impl FnOnce(&MatchStore) -> std::result::Result<(), Box<dyn Error>>) {
+        if let Some(Err(error)) = self.store.as_ref().map(|store| save(store)) {
+            self.report_store_error(match_id, &*error);
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    fn report_store_error(&self, match_id: &str, error: &dyn Error) {
+        if let Some(on_error) = &self.store_error_handler {
+            on_error(match_id, error);
+        }
+    }
+
+    /// Current application rate limits of the key used for `group`, by routing value.
+    pub fn rate_limits(&self, group: EndpointGroup) -> HashMap<String, Vec<RateLimit>> {
+        self.api_key(Some(group)).rate_limiter.status()
+    }
+
+    fn api_key(&self, group: Option<EndpointGroup>) -> Arc<ApiKey> {
+        group
+            .and_then(|group| self.keys.read().unwrap().get(&group).cloned())
+            .unwrap_or_else(|| self.default_key.clone())
+    }
+
+    fn routing(&self, short_region: bool) -> &str {
+        match &self.base_url {
+            Some(base_url) => base_url,
+            None if short_region => self.region.to_short_region(),
+            None => self.region.to_long_region()
+        }
+    }
+
     pub async fn request<T>(
-        &self, 
-        method: reqwest::Method, 
-        endpoint: String, 
-        short_region: bool, 
+        &self,
+        method: reqwest::Method,
+        endpoint: String,
+        short_region: bool,
+        query: Option<HashMap<&str, String>>
+    ) -> std::result::Result<T, Box<dyn Error>>
+    where
+        T: DeserializeOwned
+    {
+        self.keyed_request(None, method, endpoint, short_region, query).await
+    }
+
+    /// Like [`Client::request`], but uses the API key registered for `group`.
+    pub async fn request_with_group<T>(
+        &self,
+        group: EndpointGroup,
+        method: reqwest::Method,
+        endpoint: String,
+        short_region: bool,
+        query: Option<HashMap<&str, String>>
+    ) -> std::result::Result<T, Box<dyn Error>>
+    where
+        T: DeserializeOwned
+    {
+        self.keyed_request(Some(group), method, endpoint, short_region, query).await
+    }
+
+    async fn keyed_request<T>(
+        &self,
+        group: Option<EndpointGroup>,
+        method: reqwest::Method,
+        endpoint: String,
+        short_region: bool,
         query: Option<HashMap<&str, String>>
-    ) -> std::result::Result<T, Box<dyn Error>> 
+    ) -> std::result::Result<T, Box<dyn Error>>
     where
         T: DeserializeOwned
     {
-        let region = if short_region {
-            self.region.to_short_region()
-        } else {
-            self.region.to_long_region()
+        let api_key = self.api_key(group);
+        let routing = self.routing(short_region);
+        let mut retried = false;
+
+        loop {
+            let key = api_key.get();
+            let req_builder = self
+                .builder(method.clone(), &endpoint, short_region, query.clone())
+                .header("X-Riot-Token", &key);
+            let resp = self.send_limited(&api_key, routing, req_builder).await?;
+
+            let status = resp.status().as_u16();
+            if (status == 401 || status == 403) && !retried && self.replace_rejected_key(group, &api_key, &key).await {
+                retried = true;
+                continue;
+            }
+
+            return self.parse(resp).await;
+        }
+    }
+
+    async fn replace_rejected_key(&self, group: Option<EndpointGroup>, api_key: &ApiKey, rejected_key: &str) -> bool {
+        // Another request may already have swapped in a new key
+        if api_key.get() != rejected_key {
+            return true;
+        }
+
+        let replacement = match &self.key_provider {
+            Some(provider) => provider.refresh_api_key(group, rejected_key).await,
+            None => None
         };
 
-        let url = format!("https://{region}.api.riotgames.com{endpoint}");
+        match replacement {
+            Some(key) if key != rejected_key => {
+                api_key.set(key);
+                true
+            },
+            _ => false
+        }
+    }
+
+    /// Like [`Client::request`], but authenticates with a player's RSO access token
+    /// instead of the API key.
+    pub async fn request_with_token<T>(
+        &self,
+        method: reqwest::Method,
+        endpoint: String,
+        short_region: bool,
+        query: Option<HashMap<&str, String>>,
+        access_token: &str
+    ) -> std::result::Result<T, Box<dyn Error>>
+    where
+        T: DeserializeOwned
+    {
+        let req_builder = self.builder(method, &endpoint, short_region, query);
+        self.send_with_token(req_builder, short_region, access_token).await
+    }
+
+    /// Like [`Client::request_with_token`], but POSTs `body` as JSON.
+    pub async fn post_with_token<T, B>(
+        &self,
+        endpoint: String,
+        short_region: bool,
+        body: &B,
+        access_token: &str
+    ) -> std::result::Result<T, Box<dyn Error>>
+    where
+        T: DeserializeOwned,
+        B: Serialize + ?Sized
+    {
+        let req_builder = self
+            .builder(reqwest::Method::POST, &endpoint, short_region, None)
+            .json(body);
+        self.send_with_token(req_builder, short_region, access_token).await
+    }
+
+    fn builder(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        short_region: bool,
+        query: Option<HashMap<&str, String>>
+    ) -> reqwest::RequestBuilder {
+        let url = match &self.base_url {
+            Some(base_url) => format!("{base_url}{endpoint}"),
+            None => {
+                let region = self.routing(short_region);
+                format!("https://{region}.api.riotgames.com{endpoint}")
+            }
+        };
 
         let req_builder = self
             .client
             .request(method, url);
 
-        let req_builder = match query {
+        match query {
             Some(m) => req_builder.query(&m),
             None => req_builder
-        };
+        }
+    }
+
+    /// Sends a request authenticated with an RSO access token instead of the API key. It
+    /// still counts towards the application rate limits of the default key.
+    async fn send_with_token<T>(
+        &self,
+        req_builder: reqwest::RequestBuilder,
+        short_region: bool,
+        access_token: &str
+    ) -> std::result::Result<T, Box<dyn Error>>
+    where
+        T: DeserializeOwned
+    {
+        let req_builder = req_builder.bearer_auth(access_token);
+        let resp = self.send_limited(&self.default_key, self.routing(short_region), req_builder).await?;
+        self.parse(resp).await
+    }
 
-        let resp = req_builder
-            .send()
-            .await?;
+    async fn send_limited(
+        &self,
+        api_key: &ApiKey,
+        routing: &str,
+        req_builder: reqwest::RequestBuilder
+    ) -> Result<reqwest::Response> {
+        api_key.rate_limiter.acquire(routing).await;
+        let resp = req_builder.send().await?;
+        api_key.rate_limiter.update(routing, resp.status().as_u16(), resp.headers());
+        Ok(resp)
+    }
 
+    async fn parse<T>(&self, resp: reqwest::Response) -> std::result::Result<T, Box<dyn Error>>
+    where
+        T: DeserializeOwned
+    {
         let status = resp.status();
 
-        match status {
-            _ if status.as_u16() <= 300 => Ok(resp.json().await?),
-            _ => Err(Box::new(resp.json::<ApiError>().await?.status))
+        match (status, &self.drift_handler) {
+            _ if status.as_u16() > 300 => Err(Box::new(resp.json::<ApiError>().await?.status)),
+            (_, Some(on_drift)) => {
+                let endpoint = resp.url().path().to_string();
+                let parsed = lenient::from_slice::<T>(&resp.bytes().await?)?;
+
+                if !parsed.drift.is_empty() {
+                    on_drift(&endpoint, &parsed.drift);
+                }
+                Ok(parsed.value)
+            },
+            (_, None) => Ok(resp.json().await?)
         }
     }
-}
\ No newline at end of file
+}
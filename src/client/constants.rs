@@ -36,4 +36,24 @@ impl Region {
             Region::JP | Region::KR | Region::OCE => "sea",
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    Account,
+    Champion,
+    ChampionMastery,
+    LolStatus,
+    LorDeck,
+    LorInventory,
+    Match,
+    Spectator,
+    Summoner
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    pub limit: u32,
+    pub window: std::time::Duration,
+    pub count: u32
+}
@@ -0,0 +1,185 @@
+use super::constants::RateLimit;
+use reqwest::header::HeaderMap;
+use std::{collections::HashMap, sync::Mutex, time::{Duration, Instant}};
+
+struct Window {
+    limit: u32,
+    duration: Duration,
+    count: u32,
+    start: Instant
+}
+
+#[derive(Default)]
+struct Bucket {
+    windows: Vec<Window>,
+    retry_until: Option<Instant>
+}
+
+impl Bucket {
+    fn try_acquire(&mut self, now: Instant) -> Option<Duration> {
+        if let Some(retry_until) = self.retry_until {
+            if retry_until > now {
+                return Some(retry_until - now);
+            }
+            self.retry_until = None;
+        }
+
+        let mut wait = Duration::ZERO;
+        for window in self.windows.iter_mut() {
+            if now.duration_since(window.start) >= window.duration {
+                window.count = 0;
+                window.start = now;
+            }
+            if window.count >= window.limit {
+                wait = wait.max(window.start + window.duration - now);
+            }
+        }
+
+        if wait > Duration::ZERO {
+            return Some(wait);
+        }
+
+        for window in self.windows.iter_mut() {
+            window.count += 1;
+        }
+        None
+    }
+
+    fn update(&mut self, limits: &[(u32, u64)], counts: &[(u32, u64)], now: Instant) {
+        self.windows.retain(|window| limits.iter().any(|(_, secs)| window.duration.as_secs() == *secs));
+
+        for &(limit, secs) in limits {
+            let count = counts
+                .iter()
+                .find(|(_, window)| *window == secs)
+                .map(|(count, _)| *count);
+
+            match self.windows.iter_mut().find(|window| window.duration.as_secs() == secs) {
+                Some(window) => {
+                    window.limit = limit;
+                    if let Some(count) = count {
+                        if count == 1 {
+                            window.start = now;
+                        }
+                        window.count = window.count.max(count);
+                    }
+                },
+                None => self.windows.push(Window {
+                    limit,
+                    duration: Duration::from_secs(secs),
+                    count: count.unwrap_or(1),
+                    start: now
+                })
+            }
+        }
+    }
+}
+
+/// Tracks the application rate limits Riot reports for a single API key, separately for
+/// every routing value since limits are enforced per region.
+#[derive(Default)]
+pub(crate) struct RateLimiter {
+    buckets: Mutex<HashMap<String, Bucket>>
+}
+
+impl RateLimiter {
+    pub(crate) async fn acquire(&self, routing: &str) {
+        loop {
+            let wait = self
+                .buckets
+                .lock()
+                .unwrap()
+                .entry(routing.to_string())
+                .or_default()
+                .try_acquire(Instant::now());
+
+            match wait {
+                Some(duration) => tokio::time::sleep(duration).await,
+                None => return
+            }
+        }
+    }
+
+    pub(crate) fn update(&self, routing: &str, status: u16, headers: &HeaderMap) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(routing.to_string()).or_default();
+
+        let limits = parse_header(headers, "X-App-Rate-Limit");
+        if !limits.is_empty() {
+            let counts = parse_header(headers, "X-App-Rate-Limit-Count");
+            bucket.update(&limits, &counts, now);
+        }
+
+        if status == 429 {
+            let retry_after = headers
+                .get("Retry-After")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok());
+
+            if let Some(secs) = retry_after {
+                bucket.retry_until = Some(now + Duration::from_secs(secs));
+            }
+        }
+    }
+
+    pub(crate) fn status(&self) -> HashMap<String, Vec<RateLimit>> {
+        self.buckets
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(routing, bucket)| {
+                let limits = bucket.windows
+                    .iter()
+                    .map(|window| RateLimit {
+                        limit: window.limit,
+                        window: window.duration,
+                        count: window.count
+                    })
+                    .collect();
+                (routing.clone(), limits)
+            })
+            .collect()
+    }
+}
+
+fn parse_header(headers: &HeaderMap, name: &str) -> Vec<(u32, u64)> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| {
+            v.split(',')
+                .filter_map(|pair| pair.trim().split_once(':'))
+                .filter_map(|(value, secs)| Some((value.parse().ok()?, secs.parse().ok()?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_once_window_is_exhausted() {
+        let start = Instant::now();
+        let mut bucket = Bucket::default();
+        bucket.update(&[(2, 10)], &[(1, 10)], start);
+
+        assert_eq!(bucket.try_acquire(start), None);
+        let wait = bucket.try_acquire(start).expect("Expected to wait for the window");
+        assert_eq!(wait, Duration::from_secs(10));
+
+        assert_eq!(bucket.try_acquire(start + Duration::from_secs(10)), None);
+    }
+
+    #[test]
+    fn counts_from_headers_are_not_lowered() {
+        let start = Instant::now();
+        let mut bucket = Bucket::default();
+        bucket.update(&[(100, 120)], &[(5, 120)], start);
+        bucket.update(&[(100, 120)], &[(3, 120)], start);
+
+        assert_eq!(bucket.windows[0].count, 5);
+    }
+}
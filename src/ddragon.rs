@@ -0,0 +1,261 @@
+pub mod constants;
+pub mod store;
+use crate::champion::Champion;
+use crate::endpoints::constants::{MatchDto, ParticipantDto};
+use self::constants::{ChampionDto, ChampionListDto, ItemDto, ItemListDto, RuneDto, RuneTreeDto, SummonerSpellDto, SummonerSpellListDto};
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, error::Error};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const DDRAGON_BASE_URL: &str = "https://ddragon.leagueoflegends.com";
+
+/// Client for Data Dragon, Riot's static data CDN for champions, items, summoner spells and
+/// runes.
+pub struct DataDragon {
+    client: reqwest::Client,
+    base_url: String
+}
+
+impl DataDragon {
+    pub fn new() -> std::result::Result<Self, reqwest::Error> {
+        let client = reqwest::ClientBuilder::new()
+            .build()?;
+
+        Ok(DataDragon {
+            client,
+            base_url: DDRAGON_BASE_URL.to_string()
+        })
+    }
+
+    /// Fetches from `base_url` instead of Riot's CDN, e.g. a local mirror laid out like
+    /// `{base_url}/api/versions.json` and `{base_url}/cdn/{version}/data/{locale}/item.json`.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// All published versions, newest first.
+    pub async fn versions(&self) -> Result<Vec<String>> {
+        self.get("/api/versions.json").await
+    }
+
+    pub async fn latest_version(&self) -> Result<String> {
+        let versions = self.versions().await?;
+        versions
+            .into_iter()
+            .next()
+            .ok_or_else(|| "Data Dragon returned no versions".into())
+    }
+
+    pub async fn champions(&self, version: &str, locale: &str) -> Result<ChampionListDto> {
+        self.get(&format!("/cdn/{version}/data/{locale}/champion.json")).await
+    }
+
+    pub async fn items(&self, version: &str, locale: &str) -> Result<ItemListDto> {
+        self.get(&format!("/cdn/{version}/data/{locale}/item.json")).await
+    }
+
+    pub async fn summoner_spells(&self, version: &str, locale: &str) -> Result<SummonerSpellListDto> {
+        self.get(&format!("/cdn/{version}/data/{locale}/summoner.json")).await
+    }
+
+    pub async fn runes(&self, version: &str, locale: &str) -> Result<Vec<RuneTreeDto>> {
+        self.get(&format!("/cdn/{version}/data/{locale}/runesReforged.json")).await
+    }
+
+    /// Fetches champion, item, summoner spell and rune data of one version at once.
+    pub async fn static_data(&self, version: &str, locale: &str) -> Result<StaticData> {
+        let (champions, items, spells, runes) = tokio::try_join!(
+            self.champions(version, locale),
+            self.items(version, locale),
+            self.summoner_spells(version, locale),
+            self.runes(version, locale)
+        )?;
+
+        Ok(StaticData::new(version, locale, champions, items, spells, runes))
+    }
+
+    async fn get<T>(&self, path: &str) -> Result<T>
+    where
+        T: DeserializeOwned
+    {
+        let resp = self
+            .client
+            .get(format!("{}{path}", self.base_url))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(resp.json().await?)
+    }
+}
+
+/// Static data of a single Data Dragon version and locale, indexed by the ids used in the
+/// match and mastery DTOs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticData {
+    pub version: String,
+    pub locale: String,
+    champions: HashMap<Champion, ChampionDto>,
+    items: HashMap<i32, ItemDto>,
+    spells: HashMap<i32, SummonerSpellDto>,
+    rune_trees: Vec<RuneTreeDto>,
+    perks: HashMap<i32, RuneDto>
+}
+
+impl StaticData {
+    pub fn new(
+        version: &str,
+        locale: &str,
+        champions: ChampionListDto,
+        items: ItemListDto,
+        spells: SummonerSpellListDto,
+        rune_trees: Vec<RuneTreeDto>
+    ) -> Self {
+        let champions = champions
+            .data
+            .into_values()
+            .filter_map(|champion| Some((champion.champion()?, champion)))
+            .collect();
+
+        let spells = spells
+            .data
+            .into_values()
+            .filter_map(|spell| Some((spell.key.parse().ok()?, spell)))
+            .collect();
+
+        let perks = rune_trees
+            .iter()
+            .flat_map(|tree| &tree.slots)
+            .flat_map(|slot| &slot.runes)
+            .map(|rune| (rune.id, rune.clone()))
+            .collect();
+
+        StaticData {
+            version: version.to_string(),
+            locale: locale.to_string(),
+            champions,
+            items: items.data.into_iter().collect(),
+            spells,
+            rune_trees,
+            perks
+        }
+    }
+
+    pub fn champion(&self, champion: Champion) -> Option<&ChampionDto> {
+        self.champions.get(&champion)
+    }
+
+    pub fn item(&self, item_id: i32) -> Option<&ItemDto> {
+        self.items.get(&item_id)
+    }
+
+    /// Summoner spell by its numeric key, as in `ParticipantDto::summoner1_id`.
+    pub fn spell(&self, spell_id: i32) -> Option<&SummonerSpellDto> {
+        self.spells.get(&spell_id)
+    }
+
+    /// A keystone or minor rune. Stat shards are not part of Data Dragon.
+    pub fn perk(&self, perk_id: i32) -> Option<&RuneDto> {
+        self.perks.get(&perk_id)
+    }
+
+    /// A rune path such as Precision, as in `PerkStyleDto::style`.
+    pub fn rune_tree(&self, style_id: i32) -> Option<&RuneTreeDto> {
+        self.rune_trees.iter().find(|tree| tree.id == style_id)
+    }
+
+    /// The participants of `match_` with their champion, items, summoner spells and runes looked
+    /// up in this data. Ids missing from it, such as empty item slots, resolve to `None`.
+    pub fn resolve_participants<'a>(&'a self, match_: &'a MatchDto) -> Vec<ResolvedParticipant<'a>> {
+        match_
+            .info
+            .participants
+            .iter()
+            .map(|participant| self.resolve_participant(participant))
+            .collect()
+    }
+
+    pub fn resolve_participant<'a>(&'a self, participant: &'a ParticipantDto) -> ResolvedParticipant<'a> {
+        let p = participant;
+        let items = [p.item0, p.item1, p.item2, p.item3, p.item4, p.item5, p.item6].map(|id| self.item(id));
+        let style = |description: &str| p.perks.styles.iter().find(|style| style.description == description);
+
+        ResolvedParticipant {
+            participant,
+            champion: self.champion(p.champion_id),
+            items,
+            summoner_spells: [self.spell(p.summoner1_id), self.spell(p.summoner2_id)],
+            primary_tree: style("primaryStyle").and_then(|style| self.rune_tree(style.style)),
+            secondary_tree: style("subStyle").and_then(|style| self.rune_tree(style.style)),
+            perks: p
+                .perks
+                .styles
+                .iter()
+                .flat_map(|style| &style.selections)
+                .filter_map(|selection| self.perk(selection.perk))
+                .collect()
+        }
+    }
+
+    /// Makes the names of champions released after this crate was published known to
+    /// [`Champion`].
+    pub fn register_champions(&self) {
+        for (champion, data) in &self.champions {
+            Champion::register(champion.key(), &data.id, &data.name);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedParticipant<'a> {
+    pub participant: &'a ParticipantDto,
+    pub champion: Option<&'a ChampionDto>,
+    /// Item slots 0 to 6, the last being the trinket.
+    pub items: [Option<&'a ItemDto>; 7],
+    pub summoner_spells: [Option<&'a SummonerSpellDto>; 2],
+    pub primary_tree: Option<&'a RuneTreeDto>,
+    pub secondary_tree: Option<&'a RuneTreeDto>,
+    pub perks: Vec<&'a RuneDto>
+}
+
+impl ResolvedParticipant<'_> {
+    /// Display name of the champion, falling back to the internal name from the match.
+    pub fn champion_name(&self) -> &str {
+        match self.champion {
+            Some(champion) => &champion.name,
+            None => &self.participant.champion_name
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockServer;
+
+    #[tokio::test]
+    async fn versions_from_mirror() {
+        let server = MockServer::serve_dir("tests/fixtures/ddragon").await;
+        let ddragon = DataDragon::new().expect("Failed to create client").with_base_url(server.url());
+
+        assert_eq!(ddragon.latest_version().await.expect("Failed to get versions"), "12.22.1");
+        assert!(ddragon.champions("12.21.1", "xx_XX").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn static_data_lookups() {
+        let server = MockServer::serve_dir("tests/fixtures/ddragon").await;
+        let ddragon = DataDragon::new().expect("Failed to create client").with_base_url(server.url());
+        let data = ddragon.static_data("12.21.1", "en_US").await.expect("Failed to get static data");
+
+        assert_eq!(data.item(3078).map(|item| item.name.as_str()), Some("Trinity Force"));
+        assert_eq!(data.item(3077).map(|item| item.into_ids().collect()), Some(vec![3748]));
+        assert_eq!(data.spell(4).map(|spell| spell.name.as_str()), Some("Flash"));
+        assert_eq!(data.perk(8005).map(|perk| perk.name.as_str()), Some("Press the Attack"));
+        assert_eq!(data.rune_tree(8100).map(|tree| tree.name.as_str()), Some("Domination"));
+        assert_eq!(data.champion(Champion::MONKEY_KING).map(|champion| champion.name.as_str()), Some("Wukong"));
+        assert_eq!(data.perk(5005), None);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::champion::Champion;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ImageDto {
+    pub full: String,
+    pub sprite: String,
+    pub group: String,
+    pub x: i32,
+    pub y: i32,
+    pub w: i32,
+    pub h: i32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChampionListDto {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub format: String,
+    pub version: String,
+    pub data: BTreeMap<String, ChampionDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChampionDto {
+    pub version: String,
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    pub title: String,
+    pub blurb: String,
+    pub info: ChampionInfoDto,
+    pub image: ImageDto,
+    pub tags: Vec<String>,
+    pub partype: String,
+    pub stats: BTreeMap<String, f64>
+}
+
+impl ChampionDto {
+    pub fn champion(&self) -> Option<Champion> {
+        self.key.parse().ok().map(Champion::from_key)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChampionInfoDto {
+    pub attack: i32,
+    pub defense: i32,
+    pub magic: i32,
+    pub difficulty: i32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ItemListDto {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub version: String,
+    pub data: BTreeMap<i32, ItemDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ItemDto {
+    pub name: String,
+    pub description: String,
+    pub colloquial: String,
+    pub plaintext: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub from: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub into: Vec<String>,
+    pub image: ImageDto,
+    pub gold: GoldDto,
+    pub tags: Vec<String>,
+    pub maps: BTreeMap<String, bool>,
+    pub stats: BTreeMap<String, f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<i32>
+}
+
+impl ItemDto {
+    /// Ids of the items this item is built from.
+    pub fn from_ids(&self) -> impl Iterator<Item = i32> + '_ {
+        self.from.iter().filter_map(|id| id.parse().ok())
+    }
+
+    /// Ids of the items this item builds into.
+    pub fn into_ids(&self) -> impl Iterator<Item = i32> + '_ {
+        self.into.iter().filter_map(|id| id.parse().ok())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GoldDto {
+    pub base: i32,
+    pub purchasable: bool,
+    pub total: i32,
+    pub sell: i32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SummonerSpellListDto {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub version: String,
+    pub data: BTreeMap<String, SummonerSpellDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SummonerSpellDto {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub tooltip: String,
+    pub maxrank: i32,
+    pub cooldown: Vec<f64>,
+    pub cooldown_burn: String,
+    pub key: String,
+    pub summoner_level: i32,
+    pub modes: Vec<String>,
+    pub range: Vec<f64>,
+    pub range_burn: String,
+    pub image: ImageDto
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RuneTreeDto {
+    pub id: i32,
+    pub key: String,
+    pub icon: String,
+    pub name: String,
+    pub slots: Vec<RuneSlotDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RuneSlotDto {
+    pub runes: Vec<RuneDto>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneDto {
+    pub id: i32,
+    pub key: String,
+    pub icon: String,
+    pub name: String,
+    pub short_desc: String,
+    pub long_desc: String
+}
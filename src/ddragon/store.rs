@@ -0,0 +1,120 @@
+use crate::endpoints::constants::MatchDto;
+use crate::version::Patch;
+use super::{DataDragon, StaticData};
+use std::{collections::HashMap, error::Error, sync::{Arc, Mutex}};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Loads Data Dragon static data per patch on first use and keeps it, so every match is
+/// resolved against the data of the patch it was played on.
+pub struct StaticDataStore {
+    ddragon: DataDragon,
+    locale: String,
+    versions: Mutex<Option<Arc<Vec<String>>>>,
+    bundles: Mutex<HashMap<Patch, Arc<StaticData>>>
+}
+
+impl StaticDataStore {
+    pub fn new(ddragon: DataDragon, locale: impl Into<String>) -> Self {
+        StaticDataStore {
+            ddragon,
+            locale: locale.into(),
+            versions: Mutex::new(None),
+            bundles: Mutex::new(HashMap::new())
+        }
+    }
+
+    pub async fn get(&self, patch: Patch) -> Result<Arc<StaticData>> {
+        if let Some(bundle) = self.bundles.lock().unwrap().get(&patch) {
+            return Ok(bundle.clone());
+        }
+
+        let version = self.version(patch).await?;
+        let bundle = Arc::new(self.ddragon.static_data(&version, &self.locale).await?);
+
+        // A concurrent load of the same patch may have finished first
+        let mut bundles = self.bundles.lock().unwrap();
+        Ok(bundles.entry(patch).or_insert(bundle).clone())
+    }
+
+    /// Static data of the patch `match_` was played on.
+    pub async fn for_match(&self, match_: &MatchDto) -> Result<Arc<StaticData>> {
+        self.get(match_.info.patch()?).await
+    }
+
+    /// Newest Data Dragon version published for `patch`, e.g. "13.1.1" for 13.1.
+    async fn version(&self, patch: Patch) -> Result<String> {
+        let cached = self.versions.lock().unwrap().clone();
+        if let Some(version) = cached.and_then(|versions| find_version(&versions, patch)) {
+            return Ok(version);
+        }
+
+        // Not fetched yet, or the patch was released after the last fetch
+        let versions = Arc::new(self.ddragon.versions().await?);
+        *self.versions.lock().unwrap() = Some(versions.clone());
+
+        find_version(&versions, patch).ok_or_else(|| format!("No Data Dragon version for patch {patch}").into())
+    }
+}
+
+fn find_version(versions: &[String], patch: Patch) -> Option<String> {
+    versions.iter().find(|version| version.parse::<Patch>().ok() == Some(patch)).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fixtures, MockResponse, MockServer};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn resolve_match_against_its_patch() {
+        let server = MockServer::serve_dir("tests/fixtures/ddragon").await;
+        let ddragon = DataDragon::new().expect("Failed to create client").with_base_url(server.url());
+        let store = StaticDataStore::new(ddragon, "en_US");
+        let match_ = fixtures::match_();
+
+        let data = store.for_match(&match_).await.expect("Failed to load static data");
+        assert_eq!(data.version, "12.21.1");
+
+        let participants = data.resolve_participants(&match_);
+        let neeko = &participants[2];
+        assert_eq!(neeko.champion_name(), "Neeko");
+        assert_eq!(neeko.items[1].map(|item| item.name.as_str()), Some("Hextech Alternator"));
+        assert_eq!(neeko.summoner_spells.map(|spell| spell.map(|spell| spell.name.as_str())), [Some("Flash"), Some("Ignite")]);
+        assert_eq!(neeko.primary_tree.map(|tree| tree.name.as_str()), Some("Precision"));
+        assert_eq!(neeko.secondary_tree.map(|tree| tree.name.as_str()), Some("Domination"));
+        assert_eq!(participants[0].perks.first().map(|perk| perk.name.as_str()), Some("Press the Attack"));
+        assert_eq!(participants[6].champion_name(), "Kha'Zix");
+
+        let requests = server.requests().len();
+        store.for_match(&match_).await.expect("Failed to load static data");
+        assert_eq!(server.requests().len(), requests);
+
+        assert!(store.get(Patch::new(9, 1)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn refetch_versions_for_new_patch() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let counter = fetches.clone();
+        let server = MockServer::start(move |_| {
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => MockResponse::json(200, r#"["12.21.1"]"#),
+                _ => MockResponse::json(200, r#"["12.22.1","12.21.1"]"#)
+            }
+        }).await;
+        let ddragon = DataDragon::new().expect("Failed to create client").with_base_url(server.url());
+        let store = StaticDataStore::new(ddragon, "en_US");
+
+        assert_eq!(store.version(Patch::new(12, 21)).await.expect("Failed to find version"), "12.21.1");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        assert_eq!(store.version(Patch::new(12, 22)).await.expect("Failed to find version"), "12.22.1");
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+        store.version(Patch::new(12, 22)).await.expect("Failed to find version");
+        store.version(Patch::new(12, 21)).await.expect("Failed to find version");
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+}
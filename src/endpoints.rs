@@ -1,8 +1,9 @@
 pub mod constants;
 use std::collections::HashMap;
 use reqwest::Method;
-use crate::client::Client;
-use self::constants::{SummonerDTO, MatchDto, MatchTimelineDto, CurrentGameInfo, FeaturedGames, PlatformDataDto, AccountDto, ActiveShardDto, ChampionMasteryDto, ChampionInfo, Game};
+use crate::champion::Champion;
+use crate::client::{Client, constants::EndpointGroup};
+use self::constants::{SummonerDTO, MatchDto, MatchTimelineDto, CurrentGameInfo, FeaturedGames, PlatformDataDto, AccountDto, ActiveShardDto, ChampionMasteryDto, ChampionInfo, Game, CardDto, DeckDto, NewDeckDto};
 
 // type Result<T> = std::result::Result<T, reqwest::Error>;
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
@@ -11,7 +12,7 @@ fn insert_query<'a, T: ToString>(
     query: &mut HashMap<&'a str, String>, 
     k: &'a str, 
     v: &Option<T>
-) -> () {
+) {
     match v {
         Some(w) => query.insert(k, w.to_string()),
         None => None
@@ -25,7 +26,7 @@ impl Client {
         encrypted_account_id: &str
     ) -> Result<SummonerDTO> {
         let endpoint = format!("/lol/summoner/v4/summoners/by-account/{encrypted_account_id}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::Summoner, Method::GET, endpoint, true, None).await
     }
 
     pub async fn get_summoner_by_name(
@@ -33,7 +34,7 @@ impl Client {
         summoner_name: &str
     ) -> Result<SummonerDTO> {
         let endpoint = format!("/lol/summoner/v4/summoners/by-name/{summoner_name}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::Summoner, Method::GET, endpoint, true, None).await
     }
     
     pub async fn get_summoner_by_puuid(
@@ -41,7 +42,7 @@ impl Client {
         encrypted_puuid: &str
     ) -> Result<SummonerDTO> {
         let endpoint = format!("/lol/summoner/v4/summoners/by-puuid/{encrypted_puuid}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::Summoner, Method::GET, endpoint, true, None).await
     }
     
     pub async fn get_summoner_by_summoner_id(
@@ -49,10 +50,11 @@ impl Client {
         encrypted_summoner_id: &str
     ) -> Result<SummonerDTO> {
         let endpoint = format!("/lol/summoner/v4/summoners/{encrypted_summoner_id}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::Summoner, Method::GET, endpoint, true, None).await
     }
 
     // Match V5
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_matches(
         &self, 
         puuid: String, 
@@ -73,23 +75,41 @@ impl Client {
         insert_query(&mut query, "start", &start);
         insert_query(&mut query, "count", &count);
 
-        self.request(Method::GET, endpoint, false, Some(query)).await
+        self.request_with_group(EndpointGroup::Match, Method::GET, endpoint, false, Some(query)).await
     }
 
     pub async fn get_match(
         &self, 
         match_id: String
     ) -> Result<MatchDto> {
+        #[cfg(feature = "sqlite")]
+        if let Some(match_) = self.load_stored(&match_id, |store| store.get_match(&match_id)) {
+            return Ok(match_);
+        }
+
         let endpoint = format!("/lol/match/v5/matches/{match_id}");
-        self.request(Method::GET, endpoint, false, None).await
+        let match_: MatchDto = self.request_with_group(EndpointGroup::Match, Method::GET, endpoint, false, None).await?;
+
+        #[cfg(feature = "sqlite")]
+        self.save_fetched(&match_id, |store| store.save_match(&match_));
+        Ok(match_)
     }
 
     pub async fn get_match_timeline(
         &self,
         match_id: String
     ) -> Result<MatchTimelineDto> {
+        #[cfg(feature = "sqlite")]
+        if let Some(timeline) = self.load_stored(&match_id, |store| store.get_timeline(&match_id)) {
+            return Ok(timeline);
+        }
+
         let endpoint = format!("/lol/match/v5/matches/{match_id}/timeline");
-        self.request(Method::GET, endpoint, false, None).await
+        let timeline: MatchTimelineDto = self.request_with_group(EndpointGroup::Match, Method::GET, endpoint, false, None).await?;
+
+        #[cfg(feature = "sqlite")]
+        self.save_fetched(&match_id, |store| store.save_timeline(&match_id, &timeline));
+        Ok(timeline)
     }
 
     // Spectator V4
@@ -98,18 +118,18 @@ impl Client {
         encrypted_summoner_id: String
     ) -> Result<CurrentGameInfo> {
         let endpoint = format!("/lol/spectator/v4/active-games/by-summoner/{encrypted_summoner_id}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::Spectator, Method::GET, endpoint, true, None).await
     }
 
     pub async fn get_featured_games(&self) -> Result<FeaturedGames> {
         let endpoint = "/lol/spectator/v4/featured-games".to_string();
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::Spectator, Method::GET, endpoint, true, None).await
     }
 
     // LoL Status V4
     pub async fn get_platform_data(&self) -> Result<PlatformDataDto> {
         let endpoint = "/lol/status/v4/platform-data".to_string();
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::LolStatus, Method::GET, endpoint, true, None).await
     }
 
     // Account V1
@@ -118,7 +138,7 @@ impl Client {
         puuid: String
     ) -> Result<AccountDto> {
         let endpoint = format!("/riot/account/v1/accounts/by-puuid/{puuid}");
-        self.request(Method::GET, endpoint, false, None).await
+        self.request_with_group(EndpointGroup::Account, Method::GET, endpoint, false, None).await
     }
     
     pub async fn get_account_by_riot_id(
@@ -127,7 +147,15 @@ impl Client {
         tag_line: String
     ) -> Result<AccountDto> {
         let endpoint = format!("/riot/account/v1/accounts/by-riot-id/{game_name}/{tag_line}");
-        self.request(Method::GET, endpoint, false, None).await
+        self.request_with_group(EndpointGroup::Account, Method::GET, endpoint, false, None).await
+    }
+
+    pub async fn get_account_me(
+        &self,
+        access_token: &str
+    ) -> Result<AccountDto> {
+        let endpoint = "/riot/account/v1/accounts/me".to_string();
+        self.request_with_token(Method::GET, endpoint, false, None, access_token).await
     }
 
     pub async fn get_active_shard(
@@ -136,7 +164,7 @@ impl Client {
         puuid: String
     ) -> Result<ActiveShardDto> {
         let endpoint = format!("/riot/account/v1/active-shards/by-game/{game}/by-puuid/{puuid}");
-        self.request(Method::GET, endpoint, false, None).await
+        self.request_with_group(EndpointGroup::Account, Method::GET, endpoint, false, None).await
     }
 
     // Champion Mastery V4
@@ -145,16 +173,17 @@ impl Client {
         encrypted_summoner_id: String,
     ) -> Result<Vec<ChampionMasteryDto>> {
         let endpoint = format!("/lol/champion-mastery/v4/champion-masteries/by-summoner/{encrypted_summoner_id}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::ChampionMastery, Method::GET, endpoint, true, None).await
     }
     
     pub async fn get_champion_mastery_by_champion_id(
         &self,
         encrypted_summoner_id: String,
-        champion_id: i64
+        champion: Champion
     ) -> Result<ChampionMasteryDto> {
+        let champion_id = champion.key();
         let endpoint = format!("/lol/champion-mastery/v4/champion-masteries/by-summoner/{encrypted_summoner_id}/by-champion/{champion_id}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::ChampionMastery, Method::GET, endpoint, true, None).await
     }
     
     pub async fn get_top_champion_masteries(
@@ -167,7 +196,7 @@ impl Client {
         let mut query = HashMap::new();
         insert_query(&mut query, "count", &count);
 
-        self.request(Method::GET, endpoint, true, Some(query)).await
+        self.request_with_group(EndpointGroup::ChampionMastery, Method::GET, endpoint, true, Some(query)).await
     }
     
     pub async fn get_champion_mastery_score(
@@ -175,7 +204,7 @@ impl Client {
         encrypted_summoner_id: String
     ) -> Result<i32> {
         let endpoint = format!("/lol/champion-mastery/v4/scores/by-summoner/{encrypted_summoner_id}");
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::ChampionMastery, Method::GET, endpoint, true, None).await
     }
     
     // Champion V3
@@ -183,6 +212,33 @@ impl Client {
         &self
     ) -> Result<ChampionInfo> {
         let endpoint = "/lol/platform/v3/champion-rotations".to_string();
-        self.request(Method::GET, endpoint, true, None).await
+        self.request_with_group(EndpointGroup::Champion, Method::GET, endpoint, true, None).await
+    }
+
+    // LoR Inventory V1
+    pub async fn get_lor_cards_me(
+        &self,
+        access_token: &str
+    ) -> Result<Vec<CardDto>> {
+        let endpoint = "/lor/inventory/v1/cards/me".to_string();
+        self.request_with_token(Method::GET, endpoint, false, None, access_token).await
+    }
+
+    // LoR Deck V1
+    pub async fn get_lor_decks_me(
+        &self,
+        access_token: &str
+    ) -> Result<Vec<DeckDto>> {
+        let endpoint = "/lor/deck/v1/decks/me".to_string();
+        self.request_with_token(Method::GET, endpoint, false, None, access_token).await
+    }
+
+    pub async fn create_lor_deck_me(
+        &self,
+        access_token: &str,
+        deck: &NewDeckDto
+    ) -> Result<String> {
+        let endpoint = "/lor/deck/v1/decks/me".to_string();
+        self.post_with_token(endpoint, false, deck, access_token).await
     }
 }
\ No newline at end of file
@@ -1,11 +1,28 @@
-use serde::Deserialize;
+use crate::catalog::{GameMode, GameType, Map, Queue};
+use crate::champion::Champion;
+use crate::lenient;
+use crate::version::{GameVersion, ParseVersionError, Patch};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::BTreeMap, time::Duration};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 
-#[derive(Deserialize, Debug)]
+#[cfg(feature = "chrono")]
+fn datetime(millis: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(millis).unwrap_or_default()
+}
+
+fn game_time(millis: i32) -> Duration {
+    Duration::from_millis(millis.max(0) as u64)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Error {
     pub status: ErrorStatus
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ErrorStatus {
     #[serde(rename = "message")]
     pub source: String,
@@ -20,7 +37,7 @@ impl std::fmt::Display for ErrorStatus {
 
 impl std::error::Error for ErrorStatus {}
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SummonerDTO {
     pub account_id: String,
@@ -32,13 +49,20 @@ pub struct SummonerDTO {
     pub summoner_level: i64
 }
 
-#[derive(Deserialize, Debug)]
+#[cfg(feature = "chrono")]
+impl SummonerDTO {
+    pub fn revision_time(&self) -> DateTime<Utc> {
+        datetime(self.revision_date)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MatchDto {
     pub metadata: MetadataDto,
     pub info: InfoDto
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MetadataDto {
     pub data_version: String,
@@ -46,54 +70,123 @@ pub struct MetadataDto {
     pub participants: Vec<String>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct InfoDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_of_game_result: Option<String>,
     pub game_creation: i64,
     pub game_duration: i64,
-    pub game_end_timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_end_timestamp: Option<i64>,
     pub game_id: i64,
-    pub game_mode: String,
+    pub game_mode: GameMode,
     pub game_name: String,
     pub game_start_timestamp: i64,
-    pub game_type: String,
+    pub game_type: GameType,
     pub game_version: String,
-    pub map_id: i32,
+    pub map_id: Map,
     pub participants: Vec<ParticipantDto>,
     pub platform_id: String,
-    pub queue_id: i32,
+    pub queue_id: Queue,
     pub teams: Vec<TeamDto>,
     pub tournament_code: String
 }
 
-#[derive(Deserialize, Debug)]
+impl InfoDto {
+    pub fn version(&self) -> std::result::Result<GameVersion, ParseVersionError> {
+        self.game_version.parse()
+    }
+
+    pub fn patch(&self) -> std::result::Result<Patch, ParseVersionError> {
+        self.version().map(|version| version.patch())
+    }
+
+    /// Length of the game. `game_duration` is in milliseconds for games played before patch
+    /// 11.20 and in seconds afterwards, which is when `game_end_timestamp` was added.
+    pub fn game_length(&self) -> Duration {
+        let duration = self.game_duration.max(0) as u64;
+        match self.game_end_timestamp {
+            Some(_) => Duration::from_secs(duration),
+            None => Duration::from_millis(duration)
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn game_creation_time(&self) -> DateTime<Utc> {
+        datetime(self.game_creation)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn game_start_time(&self) -> DateTime<Utc> {
+        datetime(self.game_start_timestamp)
+    }
+
+    /// When the game ended. Games played before patch 11.20 have no end timestamp, so it is
+    /// derived from the start time and game length instead.
+    #[cfg(feature = "chrono")]
+    pub fn game_end_time(&self) -> DateTime<Utc> {
+        match self.game_end_timestamp {
+            Some(timestamp) => datetime(timestamp),
+            None => datetime(self.game_start_timestamp + self.game_length().as_millis() as i64)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticipantDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_in_pings: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assist_me_pings: Option<i32>,
     pub assists: i32,
     pub baron_kills: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub basic_pings: Option<i32>,
     pub bounty_level: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenges: Option<ChallengesDto>,
     pub champ_experience: i32,
     pub champ_level: i32,
-    pub champion_id: i32,
+    pub champion_id: Champion,
     pub champion_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub champion_skin_id: Option<i32>,
     pub champion_transform: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_pings: Option<i32>,
     pub consumables_purchased: i32,
     pub damage_dealt_to_buildings: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub damage_dealt_to_epic_monsters: Option<i32>,
     pub damage_dealt_to_objectives: i32,
     pub damage_dealt_to_turrets: i32,
     pub damage_self_mitigated: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub danger_pings: Option<i32>,
     pub deaths: i32,
     pub detector_wards_placed: i32,
     pub double_kills: i32,
     pub dragon_kills: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eligible_for_progression: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enemy_missing_pings: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enemy_vision_pings: Option<i32>,
     pub first_blood_assist: bool,
     pub first_blood_kill: bool,
     pub first_tower_assist: bool,
     pub first_tower_kill: bool,
     pub game_ended_in_early_surrender: bool,
     pub game_ended_in_surrender: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get_back_pings: Option<i32>,
     pub gold_earned: i32,
     pub gold_spent: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hold_pings: Option<i32>,
     pub individual_position: Position,
     pub inhibitor_kills: i32,
     pub inhibitor_takedowns: i32,
@@ -116,29 +209,62 @@ pub struct ParticipantDto {
     pub magic_damage_dealt: i32,
     pub magic_damage_dealt_to_champions: i32,
     pub magic_damage_taken: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missions: Option<MissionsDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_vision_pings: Option<i32>,
     pub neutral_minions_killed: i32,
     pub nexus_kills: i32,
-    pub nexus_takedowns: i32,
     pub nexus_lost: i32,
+    pub nexus_takedowns: i32,
     pub objectives_stolen: i32,
     pub objectives_stolen_assists: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_my_way_pings: Option<i32>,
     pub participant_id: i32,
     pub penta_kills: i32,
     pub perks: PerksDto,
     pub physical_damage_dealt: i32,
     pub physical_damage_dealt_to_champions: i32,
     pub physical_damage_taken: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_augment1: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_augment2: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_augment3: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_augment4: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_augment5: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_augment6: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_subteam_id: Option<i32>,
     pub profile_icon: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_pings: Option<i32>,
     pub puuid: String,
     pub quadra_kills: i32,
-    pub riot_id_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retreat_pings: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub riot_id_game_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub riot_id_name: Option<String>,
     pub riot_id_tagline: String,
     pub role: Role,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_bound_item: Option<i32>,
     pub sight_wards_bought_in_game: i32,
     pub spell1_casts: i32,
     pub spell2_casts: i32,
     pub spell3_casts: i32,
     pub spell4_casts: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subteam_placement: Option<i32>,
     pub summoner1_casts: i32,
     pub summoner1_id: i32,
     pub summoner2_casts: i32,
@@ -152,10 +278,14 @@ pub struct ParticipantDto {
     #[serde(rename = "timeCCingOthers")]
     pub time_ccing_others: i32,
     pub time_played: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_ally_jungle_minions_killed: Option<i32>,
     pub total_damage_dealt: i32,
     pub total_damage_dealt_to_champions: i32,
     pub total_damage_shielded_on_teammates: i32,
     pub total_damage_taken: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_enemy_jungle_minions_killed: Option<i32>,
     pub total_heal: i32,
     pub total_heals_on_teammates: i32,
     pub total_minions_killed: i32,
@@ -168,8 +298,12 @@ pub struct ParticipantDto {
     pub true_damage_dealt_to_champions: i32,
     pub true_damage_taken: i32,
     pub turret_kills: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turret_takedowns: Option<i32>,
     pub turrets_lost: i32,
     pub unreal_kills: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vision_cleared_pings: Option<i32>,
     pub vision_score: i32,
     pub vision_wards_bought_in_game: i32,
     pub wards_killed: i32,
@@ -177,28 +311,344 @@ pub struct ParticipantDto {
     pub win: bool
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengesDto {
+    #[serde(rename = "12AssistStreakCount", skip_serializing_if = "Option::is_none")]
+    pub assist_streak_count12: Option<i32>,
+    #[serde(rename = "HealFromMapSources", skip_serializing_if = "Option::is_none")]
+    pub heal_from_map_sources: Option<f64>,
+    #[serde(rename = "InfernalScalePickup", skip_serializing_if = "Option::is_none")]
+    pub infernal_scale_pickup: Option<i32>,
+    #[serde(rename = "SWARM_DefeatAatrox", skip_serializing_if = "Option::is_none")]
+    pub swarm_defeat_aatrox: Option<i32>,
+    #[serde(rename = "SWARM_DefeatBriar", skip_serializing_if = "Option::is_none")]
+    pub swarm_defeat_briar: Option<i32>,
+    #[serde(rename = "SWARM_DefeatMiniBosses", skip_serializing_if = "Option::is_none")]
+    pub swarm_defeat_mini_bosses: Option<i32>,
+    #[serde(rename = "SWARM_EvolveWeapon", skip_serializing_if = "Option::is_none")]
+    pub swarm_evolve_weapon: Option<i32>,
+    #[serde(rename = "SWARM_Have3Passives", skip_serializing_if = "Option::is_none")]
+    pub swarm_have3_passives: Option<i32>,
+    #[serde(rename = "SWARM_KillEnemy", skip_serializing_if = "Option::is_none")]
+    pub swarm_kill_enemy: Option<i32>,
+    #[serde(rename = "SWARM_PickupGold", skip_serializing_if = "Option::is_none")]
+    pub swarm_pickup_gold: Option<f64>,
+    #[serde(rename = "SWARM_ReachLevel50", skip_serializing_if = "Option::is_none")]
+    pub swarm_reach_level50: Option<i32>,
+    #[serde(rename = "SWARM_Survive15Min", skip_serializing_if = "Option::is_none")]
+    pub swarm_survive15_min: Option<i32>,
+    #[serde(rename = "SWARM_WinWith5EvolvedWeapons", skip_serializing_if = "Option::is_none")]
+    pub swarm_win_with5_evolved_weapons: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ability_uses: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aces_before15_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allied_jungle_monster_kills: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baron_buff_gold_advantage_over_threshold: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baron_takedowns: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blast_cone_opposite_opponent_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounty_gold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffs_stolen: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub complete_support_quest_in_time: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control_ward_time_coverage_in_river_or_enemy_half: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control_wards_placed: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub damage_per_minute: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub damage_taken_on_team_percentage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub danced_with_rift_herald: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deaths_by_enemy_champs: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dodge_skill_shots_small_window: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub double_aces: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dragon_takedowns: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliest_baron: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliest_dragon_takedown: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliest_elder_dragon: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub early_laning_phase_gold_exp_advantage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_heal_and_shielding: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elder_dragon_kills_with_opposing_soul: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elder_dragon_multikills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enemy_champion_immobilizations: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enemy_jungle_monster_kills: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic_monster_kills_near_enemy_jungler: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic_monster_kills_within30_seconds_of_spawn: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic_monster_steals: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic_monster_stolen_without_smite: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faster_support_quest_completion: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fastest_legendary: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_turret_killed: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_turret_killed_time: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fist_bump_participation: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flawless_aces: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_team_takedown: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_length: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get_takedowns_in_all_lanes_early_jungle_as_laner: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gold_per_minute: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub had_afk_teammate: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub had_open_nexus: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highest_champion_damage: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highest_crowd_control_score: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highest_ward_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub immobilize_and_kill_with_ally: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_buff_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_crab_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jungle_cs_before10_minutes: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jungler_kills_early_jungle: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jungler_takedowns_near_damaged_epic_monster: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k_turrets_destroyed_before_plates_fall: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kda: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kill_after_hidden_with_ally: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kill_participation: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killed_champ_took_full_team_damage_survived: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killing_sprees: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kills_near_enemy_turret: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kills_on_laners_early_jungle_as_jungler: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kills_on_other_lanes_early_jungle_as_laner: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kills_on_recently_healed_by_aram_pack: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kills_under_own_turret: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kills_with_help_from_epic_monster: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub knock_enemy_into_team_and_kill: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub land_skill_shots_early_game: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lane_minions_first10_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub laning_phase_gold_exp_advantage: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legendary_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legendary_item_used: Option<Vec<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lost_an_inhibitor: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cs_advantage_on_lane_opponent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_kill_deficit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_level_lead_lane_opponent: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mejais_full_stack_in_time: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub more_enemy_jungle_than_opponent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub most_wards_destroyed_one_sweeper: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_kill_one_spell: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_turret_rift_herald_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multikills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multikills_after_aggressive_flash: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mythic_item_used: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outer_turret_executes_before10_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outnumbered_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outnumbered_nexus_kill: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub perfect_dragon_souls_taken: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub perfect_game: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pick_kill_with_ally: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub played_champ_select_position: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poro_explosions: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quick_cleanse: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quick_first_turret: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quick_solo_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rift_herald_takedowns: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_ally_from_death: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scuttle_crab_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shortest_time_to_ace_from_first_takedown: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skillshots_dodged: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skillshots_hit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snowballs_hit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solo_baron_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solo_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solo_turrets_lategame: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stealth_wards_placed: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub survived_single_digit_hp_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub survived_three_immobilizes_in_fight: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedown_on_first_turret: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedowns: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedowns_after_gaining_level_advantage: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedowns_before_jungle_minion_spawn: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedowns_first25_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedowns_first_x_minutes: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedowns_in_alcove: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takedowns_in_enemy_fountain: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_baron_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_damage_percentage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_elder_dragon_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_rift_herald_kills: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teleport_takedowns: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_inhibitor_destroyed_time: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub three_wards_one_sweeper_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub took_large_damage_survived: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turret_plates_taken: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turret_takedowns: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turrets_taken_with_rift_herald: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub twenty_minions_in3_seconds_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub two_wards_one_sweeper_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unseen_recalls: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vision_score_advantage_lane_opponent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vision_score_per_minute: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub void_monster_kill: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ward_takedowns: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ward_takedowns_before20_m: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wards_guarded: Option<i32>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionsDto {
+    pub player_score0: i32,
+    pub player_score1: i32,
+    pub player_score2: i32,
+    pub player_score3: i32,
+    pub player_score4: i32,
+    pub player_score5: i32,
+    pub player_score6: i32,
+    pub player_score7: i32,
+    pub player_score8: i32,
+    pub player_score9: i32,
+    pub player_score10: i32,
+    pub player_score11: i32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PerksDto {
     pub stat_perks: PerkStatsDto,
     pub styles: Vec<PerkStyleDto>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PerkStatsDto {
     pub defense: i32,
     pub flex: i32,
     pub offense: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PerkStyleDto {
     pub description: String,
     pub selections: Vec<PerkStyleSelectionDto>,
     pub style: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PerkStyleSelectionDto {
     pub perk: i32,
     pub var1: i32,
@@ -206,46 +656,66 @@ pub struct PerkStyleSelectionDto {
     pub var3: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamDto {
     pub bans: Vec<BanDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feats: Option<FeatsDto>,
     pub objectives: ObjectivesDto,
     pub team_id: i32,
     pub win: bool
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct BanDto {
-    pub champion_id: i32,
+    pub champion_id: Champion,
     pub pick_turn: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ObjectivesDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub atakhan: Option<ObjectiveDto>,
     pub baron: ObjectiveDto,
     pub champion: ObjectiveDto,
     pub dragon: ObjectiveDto,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horde: Option<ObjectiveDto>,
     pub inhibitor: ObjectiveDto,
     pub rift_herald: ObjectiveDto,
     pub tower: ObjectiveDto
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct FeatsDto {
+    pub epic_monster_kill: FeatDto,
+    pub first_blood: FeatDto,
+    pub first_turret: FeatDto
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatDto {
+    pub feat_state: i32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ObjectiveDto {
     pub first: bool,
     pub kills: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MatchTimelineDto {
     pub metadata: MetadataDto,
     pub info: MatchTimelineInfo
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimelineInfo {
     pub frame_interval: i32,
@@ -254,14 +724,38 @@ pub struct MatchTimelineInfo {
     pub participants: Vec<MatchTimelineInfoParticipant>
 }
 
-#[derive(Deserialize, Debug)]
+impl MatchTimelineInfo {
+    pub fn frame_interval(&self) -> Duration {
+        game_time(self.frame_interval)
+    }
+
+    pub fn participant_id(&self, puuid: &str) -> Option<i32> {
+        self.participants
+            .iter()
+            .find(|participant| participant.puuid == puuid)
+            .map(|participant| participant.participant_id)
+    }
+
+    /// The frames of the participant with the given PUUID, one per timeline frame.
+    pub fn participant_frames<'a>(
+        &'a self,
+        puuid: &str
+    ) -> impl Iterator<Item = &'a MatchTimelineInfoFrameParticipantFrame> + 'a {
+        let participant_id = self.participant_id(puuid);
+        self.frames
+            .iter()
+            .filter_map(move |frame| frame.participant_frames.get(participant_id?))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimelineInfoParticipant {
     pub participant_id: i32,
     pub puuid: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimelineInfoFrame {
     pub events: Vec<MatchTimelineInfoFrameEvent>,
@@ -269,31 +763,63 @@ pub struct MatchTimelineInfoFrame {
     pub timestamp: i32
 }
 
-#[derive(Deserialize, Debug)]
-pub struct MatchTimelineInfoFrameParticipantFrames {
-    #[serde(rename = "1")]
-    pub one: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "2")]
-    pub two: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "3")]
-    pub three: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "4")]
-    pub four: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "5")]
-    pub five: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "6")]
-    pub six: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "7")]
-    pub seven: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "8")]
-    pub eight: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "9")]
-    pub nine: MatchTimelineInfoFrameParticipantFrame,
-    #[serde(rename = "10")]
-    pub ten: MatchTimelineInfoFrameParticipantFrame,
-}
-
-#[derive(Deserialize, Debug)]
+impl MatchTimelineInfoFrame {
+    /// Time since the start of the game.
+    pub fn time(&self) -> Duration {
+        game_time(self.timestamp)
+    }
+}
+
+/// Participant frames keyed by participant id. Summoner's Rift games have ids 1 to 10, while
+/// Arena games go up to 16.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(transparent)]
+pub struct MatchTimelineInfoFrameParticipantFrames(BTreeMap<i32, MatchTimelineInfoFrameParticipantFrame>);
+
+impl MatchTimelineInfoFrameParticipantFrames {
+    pub fn get(&self, participant_id: i32) -> Option<&MatchTimelineInfoFrameParticipantFrame> {
+        self.0.get(&participant_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MatchTimelineInfoFrameParticipantFrame> {
+        self.0.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::ops::Index<i32> for MatchTimelineInfoFrameParticipantFrames {
+    type Output = MatchTimelineInfoFrameParticipantFrame;
+
+    fn index(&self, participant_id: i32) -> &Self::Output {
+        &self.0[&participant_id]
+    }
+}
+
+impl<'a> IntoIterator for &'a MatchTimelineInfoFrameParticipantFrames {
+    type Item = &'a MatchTimelineInfoFrameParticipantFrame;
+    type IntoIter = std::collections::btree_map::Values<'a, i32, MatchTimelineInfoFrameParticipantFrame>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.values()
+    }
+}
+
+impl FromIterator<MatchTimelineInfoFrameParticipantFrame> for MatchTimelineInfoFrameParticipantFrames {
+    fn from_iter<I: IntoIterator<Item = MatchTimelineInfoFrameParticipantFrame>>(iter: I) -> Self {
+        MatchTimelineInfoFrameParticipantFrames(
+            iter.into_iter().map(|frame| (frame.participant_id, frame)).collect()
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimelineInfoFrameParticipantFrame {
     pub champion_stats: MatchTimelineInfoFrameParticipantFrameChampionStats,
@@ -310,7 +836,7 @@ pub struct MatchTimelineInfoFrameParticipantFrame {
     pub xp: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimelineInfoFrameParticipantFrameChampionStats {
     pub ability_haste: i32,
@@ -340,7 +866,7 @@ pub struct MatchTimelineInfoFrameParticipantFrameChampionStats {
 }
 
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimelineInfoFrameParticipantFrameDamageStats {
     pub magic_damage_done: i32,
@@ -357,54 +883,718 @@ pub struct MatchTimelineInfoFrameParticipantFrameDamageStats {
     pub true_damage_taken: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MatchTimelinPosition {
     pub x: i32,
     pub y: i32
 }
 
-#[derive(Deserialize, Debug)]
+/// A timeline event, with exactly the fields Riot sends for its [`EventType`]. Any other fields
+/// of [`RawMatchTimelineInfoFrameEvent`] an event arrives with are kept in its `extra` map, so
+/// they survive a round trip. Event types this enum does not know yet are kept as
+/// [`MatchTimelineInfoFrameEvent::Unknown`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "RawMatchTimelineInfoFrameEvent", into = "RawMatchTimelineInfoFrameEvent")]
+pub enum MatchTimelineInfoFrameEvent {
+    AscendedEvent {
+        timestamp: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    BuildingKill {
+        timestamp: i32,
+        killer_id: i32,
+        team_id: i32,
+        building_type: BuildingType,
+        lane_type: LaneType,
+        tower_type: Option<TowerType>,
+        assisting_participant_ids: Vec<i32>,
+        bounty: Option<i32>,
+        position: MatchTimelinPosition,
+        extra: serde_json::Map<String, Value>
+    },
+    CapturePoint {
+        timestamp: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    ChampionKill {
+        timestamp: i32,
+        killer_id: i32,
+        victim_id: i32,
+        assisting_participant_ids: Vec<i32>,
+        bounty: i32,
+        shutdown_bounty: i32,
+        kill_streak_length: i32,
+        position: MatchTimelinPosition,
+        victim_damage_dealt: Vec<MatchTimelineInfoFrameEventVictimDamageReceived>,
+        victim_damage_received: Vec<MatchTimelineInfoFrameEventVictimDamageReceived>,
+        extra: serde_json::Map<String, Value>
+    },
+    ChampionSpecialKill {
+        timestamp: i32,
+        killer_id: i32,
+        kill_type: KillType,
+        multi_kill_length: Option<i32>,
+        position: MatchTimelinPosition,
+        extra: serde_json::Map<String, Value>
+    },
+    ChampionTransform {
+        timestamp: i32,
+        participant_id: i32,
+        transform_type: TransformType,
+        extra: serde_json::Map<String, Value>
+    },
+    DragonSoulGiven {
+        timestamp: i32,
+        team_id: i32,
+        name: DragonSoul,
+        extra: serde_json::Map<String, Value>
+    },
+    EliteMonsterKill {
+        timestamp: i32,
+        killer_id: i32,
+        killer_team_id: i32,
+        monster_type: MonsterType,
+        monster_sub_type: Option<MonsterSubType>,
+        assisting_participant_ids: Vec<i32>,
+        bounty: Option<i32>,
+        position: MatchTimelinPosition,
+        extra: serde_json::Map<String, Value>
+    },
+    FeatUpdate {
+        timestamp: i32,
+        team_id: i32,
+        feat_type: i32,
+        feat_value: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    GameEnd {
+        timestamp: i32,
+        real_timestamp: Option<i64>,
+        game_id: i64,
+        winning_team: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    ItemDestroyed {
+        timestamp: i32,
+        participant_id: i32,
+        item_id: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    ItemPurchased {
+        timestamp: i32,
+        participant_id: i32,
+        item_id: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    ItemSold {
+        timestamp: i32,
+        participant_id: i32,
+        item_id: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    ItemUndo {
+        timestamp: i32,
+        participant_id: i32,
+        before_id: i32,
+        after_id: i32,
+        gold_gain: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    LevelUp {
+        timestamp: i32,
+        participant_id: i32,
+        level: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    ObjectiveBountyFinish {
+        timestamp: i32,
+        team_id: i32,
+        extra: serde_json::Map<String, Value>
+    },
+    ObjectiveBountyPrestart {
+        timestamp: i32,
+        team_id: i32,
+        actual_start_time: i64,
+        extra: serde_json::Map<String, Value>
+    },
+    PauseEnd {
+        timestamp: i32,
+        real_timestamp: Option<i64>,
+        extra: serde_json::Map<String, Value>
+    },
+    PauseStart {
+        timestamp: i32,
+        real_timestamp: Option<i64>,
+        extra: serde_json::Map<String, Value>
+    },
+    SkillLevelUp {
+        timestamp: i32,
+        participant_id: i32,
+        skill_slot: i32,
+        level_up_type: LevelUpType,
+        extra: serde_json::Map<String, Value>
+    },
+    TurretPlateDestroyed {
+        timestamp: i32,
+        killer_id: i32,
+        team_id: i32,
+        lane_type: LaneType,
+        position: MatchTimelinPosition,
+        extra: serde_json::Map<String, Value>
+    },
+    WardKill {
+        timestamp: i32,
+        killer_id: i32,
+        ward_type: WardType,
+        extra: serde_json::Map<String, Value>
+    },
+    WardPlaced {
+        timestamp: i32,
+        creator_id: i32,
+        ward_type: WardType,
+        extra: serde_json::Map<String, Value>
+    },
+    Unknown(Box<RawMatchTimelineInfoFrameEvent>)
+}
+
+impl MatchTimelineInfoFrameEvent {
+    pub fn timestamp(&self) -> i32 {
+        use MatchTimelineInfoFrameEvent::*;
+
+        match self {
+            AscendedEvent { timestamp, .. }
+            | BuildingKill { timestamp, .. }
+            | CapturePoint { timestamp, .. }
+            | ChampionKill { timestamp, .. }
+            | ChampionSpecialKill { timestamp, .. }
+            | ChampionTransform { timestamp, .. }
+            | DragonSoulGiven { timestamp, .. }
+            | EliteMonsterKill { timestamp, .. }
+            | FeatUpdate { timestamp, .. }
+            | GameEnd { timestamp, .. }
+            | ItemDestroyed { timestamp, .. }
+            | ItemPurchased { timestamp, .. }
+            | ItemSold { timestamp, .. }
+            | ItemUndo { timestamp, .. }
+            | LevelUp { timestamp, .. }
+            | ObjectiveBountyFinish { timestamp, .. }
+            | ObjectiveBountyPrestart { timestamp, .. }
+            | PauseEnd { timestamp, .. }
+            | PauseStart { timestamp, .. }
+            | SkillLevelUp { timestamp, .. }
+            | TurretPlateDestroyed { timestamp, .. }
+            | WardKill { timestamp, .. }
+            | WardPlaced { timestamp, .. } => *timestamp,
+            Unknown(raw) => raw.timestamp
+        }
+    }
+
+    /// Time since the start of the game.
+    pub fn time(&self) -> Duration {
+        game_time(self.timestamp())
+    }
+
+    /// Fields the event arrived with that its variant does not model, or `None` for
+    /// [`MatchTimelineInfoFrameEvent::Unknown`], which keeps every field.
+    pub fn extra(&self) -> Option<&serde_json::Map<String, Value>> {
+        use MatchTimelineInfoFrameEvent::*;
+
+        match self {
+            AscendedEvent { extra, .. }
+            | BuildingKill { extra, .. }
+            | CapturePoint { extra, .. }
+            | ChampionKill { extra, .. }
+            | ChampionSpecialKill { extra, .. }
+            | ChampionTransform { extra, .. }
+            | DragonSoulGiven { extra, .. }
+            | EliteMonsterKill { extra, .. }
+            | FeatUpdate { extra, .. }
+            | GameEnd { extra, .. }
+            | ItemDestroyed { extra, .. }
+            | ItemPurchased { extra, .. }
+            | ItemSold { extra, .. }
+            | ItemUndo { extra, .. }
+            | LevelUp { extra, .. }
+            | ObjectiveBountyFinish { extra, .. }
+            | ObjectiveBountyPrestart { extra, .. }
+            | PauseEnd { extra, .. }
+            | PauseStart { extra, .. }
+            | SkillLevelUp { extra, .. }
+            | TurretPlateDestroyed { extra, .. }
+            | WardKill { extra, .. }
+            | WardPlaced { extra, .. } => Some(extra),
+            Unknown(_) => None
+        }
+    }
+
+    /// Wall-clock time of game end and pause events.
+    #[cfg(feature = "chrono")]
+    pub fn real_time(&self) -> Option<DateTime<Utc>> {
+        use MatchTimelineInfoFrameEvent::*;
+
+        match self {
+            GameEnd { real_timestamp, .. }
+            | PauseEnd { real_timestamp, .. }
+            | PauseStart { real_timestamp, .. } => real_timestamp.map(datetime),
+            Unknown(raw) => raw.real_timestamp.map(datetime),
+            _ => None
+        }
+    }
+
+    pub fn event_type(&self) -> EventType {
+        use MatchTimelineInfoFrameEvent::*;
+
+        match self {
+            AscendedEvent { .. } => EventType::AscendedEvent,
+            BuildingKill { .. } => EventType::BuildingKill,
+            CapturePoint { .. } => EventType::CapturePoint,
+            ChampionKill { .. } => EventType::ChampionKill,
+            ChampionSpecialKill { .. } => EventType::ChampionSpecialKill,
+            ChampionTransform { .. } => EventType::ChampionTransform,
+            DragonSoulGiven { .. } => EventType::DragonSoulGiven,
+            EliteMonsterKill { .. } => EventType::EliteMonsterKill,
+            FeatUpdate { .. } => EventType::FeatUpdate,
+            GameEnd { .. } => EventType::GameEnd,
+            ItemDestroyed { .. } => EventType::ItemDestroyed,
+            ItemPurchased { .. } => EventType::ItemPurchased,
+            ItemSold { .. } => EventType::ItemSold,
+            ItemUndo { .. } => EventType::ItemUndo,
+            LevelUp { .. } => EventType::LevelUp,
+            ObjectiveBountyFinish { .. } => EventType::ObjectiveBountyFinish,
+            ObjectiveBountyPrestart { .. } => EventType::ObjectiveBountyPrestart,
+            PauseEnd { .. } => EventType::PauseEnd,
+            PauseStart { .. } => EventType::PauseStart,
+            SkillLevelUp { .. } => EventType::SkillLevelUp,
+            TurretPlateDestroyed { .. } => EventType::TurretPlateDestroyed,
+            WardKill { .. } => EventType::WardKill,
+            WardPlaced { .. } => EventType::WardPlaced,
+            Unknown(raw) => raw.type_.clone()
+        }
+    }
+}
+
+/// The flat form timeline events are sent in, where every field besides the timestamp and type
+/// is optional.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct MatchTimelineInfoFrameEvent {
+pub struct RawMatchTimelineInfoFrameEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub real_timestamp: Option<i64>,
     pub timestamp: i32,
     #[serde(rename = "type")]
     pub type_: EventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub participant_id: Option<i32>,
-    pub level_up_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level_up_type: Option<LevelUpType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skill_slot: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_id: Option<i32>,
-    pub ward_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ward_type: Option<WardType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub level: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub assisting_participant_ids: Option<Vec<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bounty: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub kill_streak_length: Option<i32>,
-    pub killed_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_team_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<MatchTimelinPosition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub victim_damage_dealt: Option<Vec<MatchTimelineInfoFrameEventVictimDamageReceived>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub victim_damage_received: Option<Vec<MatchTimelineInfoFrameEventVictimDamageReceived>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub victim_id: Option<i32>,
-    pub kill_type: Option<String>,
-    pub lane_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kill_type: Option<KillType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lane_type: Option<LaneType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub multi_kill_length: Option<i32>,
-    pub monster_type: Option<String>,
-    pub monster_sub_type: Option<String>,
-    pub building_type: Option<String>,
-    pub tower_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monster_type: Option<MonsterType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monster_sub_type: Option<MonsterSubType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub building_type: Option<BuildingType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tower_type: Option<TowerType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub after_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub before_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gold_gain: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub winning_team: Option<i32>,
-    pub transform_type: Option<String>,
-    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_type: Option<TransformType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<DragonSoul>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shutdown_bounty: Option<i32>,
-    pub actual_start_time: Option<i64>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feat_type: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feat_value: Option<i32>
+}
+
+impl Default for EventType {
+    fn default() -> Self {
+        EventType::Unknown(String::new())
+    }
+}
+
+/// Fails on a missing field, unless deserializing leniently, where it becomes a default.
+fn required<T>(value: Option<T>, field: &str, event_type: &EventType) -> std::result::Result<T, String>
+where
+    T: DeserializeOwned
+{
+    value
+        .or_else(|| lenient::missing_field(field))
+        .ok_or_else(|| format!("missing field `{field}` in {event_type} event"))
+}
+
+/// The fields of `raw` that are still set, besides its timestamp and type.
+fn leftover_fields(raw: &RawMatchTimelineInfoFrameEvent) -> serde_json::Map<String, Value> {
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(raw) else {
+        return serde_json::Map::new();
+    };
+    fields.remove("timestamp");
+    fields.remove("type");
+    fields
+}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+impl TryFrom<RawMatchTimelineInfoFrameEvent> for MatchTimelineInfoFrameEvent {
+    type Error = String;
+
+    fn try_from(mut raw: RawMatchTimelineInfoFrameEvent) -> std::result::Result<Self, Self::Error> {
+        use MatchTimelineInfoFrameEvent::*;
+
+        let t = &raw.type_;
+        let timestamp = raw.timestamp;
+
+        let event = match t {
+            EventType::AscendedEvent => AscendedEvent { timestamp, extra: leftover_fields(&raw) },
+            EventType::BuildingKill => BuildingKill {
+                timestamp,
+                killer_id: required(raw.killer_id.take(), "killerId", t)?,
+                team_id: required(raw.team_id.take(), "teamId", t)?,
+                building_type: required(raw.building_type.take(), "buildingType", t)?,
+                lane_type: required(raw.lane_type.take(), "laneType", t)?,
+                tower_type: raw.tower_type.take(),
+                assisting_participant_ids: raw.assisting_participant_ids.take().unwrap_or_default(),
+                bounty: raw.bounty.take(),
+                position: required(raw.position.take(), "position", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::CapturePoint => CapturePoint { timestamp, extra: leftover_fields(&raw) },
+            EventType::ChampionKill => ChampionKill {
+                timestamp,
+                killer_id: required(raw.killer_id.take(), "killerId", t)?,
+                victim_id: required(raw.victim_id.take(), "victimId", t)?,
+                assisting_participant_ids: raw.assisting_participant_ids.take().unwrap_or_default(),
+                bounty: required(raw.bounty.take(), "bounty", t)?,
+                shutdown_bounty: required(raw.shutdown_bounty.take(), "shutdownBounty", t)?,
+                kill_streak_length: required(raw.kill_streak_length.take(), "killStreakLength", t)?,
+                position: required(raw.position.take(), "position", t)?,
+                victim_damage_dealt: raw.victim_damage_dealt.take().unwrap_or_default(),
+                victim_damage_received: raw.victim_damage_received.take().unwrap_or_default(),
+                extra: leftover_fields(&raw)
+            },
+            EventType::ChampionSpecialKill => ChampionSpecialKill {
+                timestamp,
+                killer_id: required(raw.killer_id.take(), "killerId", t)?,
+                kill_type: required(raw.kill_type.take(), "killType", t)?,
+                multi_kill_length: raw.multi_kill_length.take(),
+                position: required(raw.position.take(), "position", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::ChampionTransform => ChampionTransform {
+                timestamp,
+                participant_id: required(raw.participant_id.take(), "participantId", t)?,
+                transform_type: required(raw.transform_type.take(), "transformType", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::DragonSoulGiven => DragonSoulGiven {
+                timestamp,
+                team_id: required(raw.team_id.take(), "teamId", t)?,
+                name: required(raw.name.take(), "name", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::EliteMonsterKill => EliteMonsterKill {
+                timestamp,
+                killer_id: required(raw.killer_id.take(), "killerId", t)?,
+                killer_team_id: required(raw.killer_team_id.take(), "killerTeamId", t)?,
+                monster_type: required(raw.monster_type.take(), "monsterType", t)?,
+                monster_sub_type: raw.monster_sub_type.take(),
+                assisting_participant_ids: raw.assisting_participant_ids.take().unwrap_or_default(),
+                bounty: raw.bounty.take(),
+                position: required(raw.position.take(), "position", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::FeatUpdate => FeatUpdate {
+                timestamp,
+                team_id: required(raw.team_id.take(), "teamId", t)?,
+                feat_type: required(raw.feat_type.take(), "featType", t)?,
+                feat_value: required(raw.feat_value.take(), "featValue", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::GameEnd => GameEnd {
+                timestamp,
+                real_timestamp: raw.real_timestamp.take(),
+                game_id: required(raw.game_id.take(), "gameId", t)?,
+                winning_team: required(raw.winning_team.take(), "winningTeam", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::ItemDestroyed => ItemDestroyed {
+                timestamp,
+                participant_id: required(raw.participant_id.take(), "participantId", t)?,
+                item_id: required(raw.item_id.take(), "itemId", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::ItemPurchased => ItemPurchased {
+                timestamp,
+                participant_id: required(raw.participant_id.take(), "participantId", t)?,
+                item_id: required(raw.item_id.take(), "itemId", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::ItemSold => ItemSold {
+                timestamp,
+                participant_id: required(raw.participant_id.take(), "participantId", t)?,
+                item_id: required(raw.item_id.take(), "itemId", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::ItemUndo => ItemUndo {
+                timestamp,
+                participant_id: required(raw.participant_id.take(), "participantId", t)?,
+                before_id: required(raw.before_id.take(), "beforeId", t)?,
+                after_id: required(raw.after_id.take(), "afterId", t)?,
+                gold_gain: required(raw.gold_gain.take(), "goldGain", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::LevelUp => LevelUp {
+                timestamp,
+                participant_id: required(raw.participant_id.take(), "participantId", t)?,
+                level: required(raw.level.take(), "level", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::ObjectiveBountyFinish => ObjectiveBountyFinish {
+                timestamp,
+                team_id: required(raw.team_id.take(), "teamId", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::ObjectiveBountyPrestart => ObjectiveBountyPrestart {
+                timestamp,
+                team_id: required(raw.team_id.take(), "teamId", t)?,
+                actual_start_time: required(raw.actual_start_time.take(), "actualStartTime", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::PauseEnd => PauseEnd {
+                timestamp,
+                real_timestamp: raw.real_timestamp.take(),
+                extra: leftover_fields(&raw)
+            },
+            EventType::PauseStart => PauseStart {
+                timestamp,
+                real_timestamp: raw.real_timestamp.take(),
+                extra: leftover_fields(&raw)
+            },
+            EventType::SkillLevelUp => SkillLevelUp {
+                timestamp,
+                participant_id: required(raw.participant_id.take(), "participantId", t)?,
+                skill_slot: required(raw.skill_slot.take(), "skillSlot", t)?,
+                level_up_type: required(raw.level_up_type.take(), "levelUpType", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::TurretPlateDestroyed => TurretPlateDestroyed {
+                timestamp,
+                killer_id: required(raw.killer_id.take(), "killerId", t)?,
+                team_id: required(raw.team_id.take(), "teamId", t)?,
+                lane_type: required(raw.lane_type.take(), "laneType", t)?,
+                position: required(raw.position.take(), "position", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::WardKill => WardKill {
+                timestamp,
+                killer_id: required(raw.killer_id.take(), "killerId", t)?,
+                ward_type: required(raw.ward_type.take(), "wardType", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::WardPlaced => WardPlaced {
+                timestamp,
+                creator_id: required(raw.creator_id.take(), "creatorId", t)?,
+                ward_type: required(raw.ward_type.take(), "wardType", t)?,
+                extra: leftover_fields(&raw)
+            },
+            EventType::Unknown(_) => Unknown(Box::new(raw))
+        };
+
+        Ok(event)
+    }
+}
+
+impl From<MatchTimelineInfoFrameEvent> for RawMatchTimelineInfoFrameEvent {
+    fn from(event: MatchTimelineInfoFrameEvent) -> Self {
+        use MatchTimelineInfoFrameEvent::*;
+
+        let type_ = event.event_type();
+        let timestamp = event.timestamp();
+        let mut fields = event.extra().cloned().unwrap_or_default();
+        fields.insert("timestamp".to_string(), timestamp.into());
+        fields.insert("type".to_string(), type_.as_str().into());
+        let raw = serde_json::from_value(Value::Object(fields))
+            .unwrap_or_else(|_| RawMatchTimelineInfoFrameEvent { timestamp, type_, ..Default::default() });
+
+        match event {
+            AscendedEvent { .. } | CapturePoint { .. } => raw,
+            BuildingKill { killer_id, team_id, building_type, lane_type, tower_type, assisting_participant_ids, bounty, position, .. } => RawMatchTimelineInfoFrameEvent {
+                killer_id: Some(killer_id),
+                team_id: Some(team_id),
+                building_type: Some(building_type),
+                lane_type: Some(lane_type),
+                tower_type,
+                assisting_participant_ids: non_empty(assisting_participant_ids),
+                bounty,
+                position: Some(position),
+                ..raw
+            },
+            ChampionKill { killer_id, victim_id, assisting_participant_ids, bounty, shutdown_bounty, kill_streak_length, position, victim_damage_dealt, victim_damage_received, .. } => RawMatchTimelineInfoFrameEvent {
+                killer_id: Some(killer_id),
+                victim_id: Some(victim_id),
+                assisting_participant_ids: non_empty(assisting_participant_ids),
+                bounty: Some(bounty),
+                shutdown_bounty: Some(shutdown_bounty),
+                kill_streak_length: Some(kill_streak_length),
+                position: Some(position),
+                victim_damage_dealt: non_empty(victim_damage_dealt),
+                victim_damage_received: non_empty(victim_damage_received),
+                ..raw
+            },
+            ChampionSpecialKill { killer_id, kill_type, multi_kill_length, position, .. } => RawMatchTimelineInfoFrameEvent {
+                killer_id: Some(killer_id),
+                kill_type: Some(kill_type),
+                multi_kill_length,
+                position: Some(position),
+                ..raw
+            },
+            ChampionTransform { participant_id, transform_type, .. } => RawMatchTimelineInfoFrameEvent {
+                participant_id: Some(participant_id),
+                transform_type: Some(transform_type),
+                ..raw
+            },
+            DragonSoulGiven { team_id, name, .. } => RawMatchTimelineInfoFrameEvent {
+                team_id: Some(team_id),
+                name: Some(name),
+                ..raw
+            },
+            EliteMonsterKill { killer_id, killer_team_id, monster_type, monster_sub_type, assisting_participant_ids, bounty, position, .. } => RawMatchTimelineInfoFrameEvent {
+                killer_id: Some(killer_id),
+                killer_team_id: Some(killer_team_id),
+                monster_type: Some(monster_type),
+                monster_sub_type,
+                assisting_participant_ids: non_empty(assisting_participant_ids),
+                bounty,
+                position: Some(position),
+                ..raw
+            },
+            FeatUpdate { team_id, feat_type, feat_value, .. } => RawMatchTimelineInfoFrameEvent {
+                team_id: Some(team_id),
+                feat_type: Some(feat_type),
+                feat_value: Some(feat_value),
+                ..raw
+            },
+            GameEnd { real_timestamp, game_id, winning_team, .. } => RawMatchTimelineInfoFrameEvent {
+                real_timestamp,
+                game_id: Some(game_id),
+                winning_team: Some(winning_team),
+                ..raw
+            },
+            ItemDestroyed { participant_id, item_id, .. }
+            | ItemPurchased { participant_id, item_id, .. }
+            | ItemSold { participant_id, item_id, .. } => RawMatchTimelineInfoFrameEvent {
+                participant_id: Some(participant_id),
+                item_id: Some(item_id),
+                ..raw
+            },
+            ItemUndo { participant_id, before_id, after_id, gold_gain, .. } => RawMatchTimelineInfoFrameEvent {
+                participant_id: Some(participant_id),
+                before_id: Some(before_id),
+                after_id: Some(after_id),
+                gold_gain: Some(gold_gain),
+                ..raw
+            },
+            LevelUp { participant_id, level, .. } => RawMatchTimelineInfoFrameEvent {
+                participant_id: Some(participant_id),
+                level: Some(level),
+                ..raw
+            },
+            ObjectiveBountyFinish { team_id, .. } => RawMatchTimelineInfoFrameEvent {
+                team_id: Some(team_id),
+                ..raw
+            },
+            ObjectiveBountyPrestart { team_id, actual_start_time, .. } => RawMatchTimelineInfoFrameEvent {
+                team_id: Some(team_id),
+                actual_start_time: Some(actual_start_time),
+                ..raw
+            },
+            PauseEnd { real_timestamp, .. } | PauseStart { real_timestamp, .. } => RawMatchTimelineInfoFrameEvent {
+                real_timestamp,
+                ..raw
+            },
+            SkillLevelUp { participant_id, skill_slot, level_up_type, .. } => RawMatchTimelineInfoFrameEvent {
+                participant_id: Some(participant_id),
+                skill_slot: Some(skill_slot),
+                level_up_type: Some(level_up_type),
+                ..raw
+            },
+            TurretPlateDestroyed { killer_id, team_id, lane_type, position, .. } => RawMatchTimelineInfoFrameEvent {
+                killer_id: Some(killer_id),
+                team_id: Some(team_id),
+                lane_type: Some(lane_type),
+                position: Some(position),
+                ..raw
+            },
+            WardKill { killer_id, ward_type, .. } => RawMatchTimelineInfoFrameEvent {
+                killer_id: Some(killer_id),
+                ward_type: Some(ward_type),
+                ..raw
+            },
+            WardPlaced { creator_id, ward_type, .. } => RawMatchTimelineInfoFrameEvent {
+                creator_id: Some(creator_id),
+                ward_type: Some(ward_type),
+                ..raw
+            },
+            Unknown(raw) => *raw
+        }
+    }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimelineInfoFrameEventVictimDamageReceived {
     pub basic: bool,
@@ -419,40 +1609,40 @@ pub struct MatchTimelineInfoFrameEventVictimDamageReceived {
     pub type_: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CurrentGameInfo {
     pub game_id: i64,
-    pub game_type: String,
+    pub game_type: GameType,
     pub game_start_time: i64,
-    pub map_id: i64,
+    pub map_id: Map,
     pub game_length: i64,
     pub platform_id: String,
-    pub game_mode: String,
+    pub game_mode: GameMode,
     pub banned_champions: Vec<BannedChampion>,
-    pub game_queue_config_id: i64,
+    pub game_queue_config_id: Queue,
     pub observers: Observer,
     pub participants: Vec<CurrentGameParticipant>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct BannedChampion {
     pub pick_turn: i32,
-    pub champion_id: i64,
+    pub champion_id: Champion,
     pub team_id: i64
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Observer {
     pub encryption_key: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CurrentGameParticipant {
-    pub champion_id: i64,
+    pub champion_id: Champion,
     pub perks: Perks,
     pub profile_icon_id: i64,
     pub bot: bool,
@@ -463,7 +1653,7 @@ pub struct CurrentGameParticipant {
     pub game_customization_objects: Vec<GameCustomizationObject>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Perks {
     pub perk_ids: Vec<i64>,
@@ -471,36 +1661,36 @@ pub struct Perks {
     pub perk_sub_style: i64
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct GameCustomizationObject {
     pub category: String,
     pub content: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FeaturedGames {
     pub game_list: Vec<FeaturedGameInfo>,
     pub client_refresh_interval: i64
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FeaturedGameInfo {
-    pub game_mode: String,
+    pub game_mode: GameMode,
     pub game_length: i64,
-    pub map_id: i64,
-    pub game_type: String,
+    pub map_id: Map,
+    pub game_type: GameType,
     pub banned_champions: Vec<BannedChampion>,
     pub game_id: i64,
     pub observers: Observer,
-    pub game_queue_config_id: i64,
+    pub game_queue_config_id: Queue,
     pub game_start_time: i64,
     pub participants: Vec<Participant>,
     pub platform_id: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Participant {
     pub bot: bool,
@@ -508,11 +1698,11 @@ pub struct Participant {
     pub spell2_id: i64,
     pub profile_icon_id: i64,
     pub summoner_name: String,
-    pub champion_id: i64,
+    pub champion_id: Champion,
     pub team_id: i64
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PlatformDataDto {
     pub id: String,
     pub name: String,
@@ -521,7 +1711,7 @@ pub struct PlatformDataDto {
     pub incidents: Vec<StatusDto>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct StatusDto {
     pub id: i32,
     pub maintenance_status: Option<MaintenanceStatus>,
@@ -534,13 +1724,13 @@ pub struct StatusDto {
     pub platforms: Vec<Platform>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ContentDto {
     pub locale: String,
     pub content: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct UpdateDto {
     pub id: i32,
     pub author: String,
@@ -550,7 +1740,7 @@ pub struct UpdateDto {
     pub updated_at: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountDto {
     pub puuid: String,
@@ -558,7 +1748,7 @@ pub struct AccountDto {
     pub tag_line: Option<String>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveShardDto {
     pub puuid: String,
@@ -566,12 +1756,31 @@ pub struct ActiveShardDto {
     pub active_shard: String
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CardDto {
+    pub code: String,
+    pub count: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeckDto {
+    pub id: String,
+    pub name: String,
+    pub code: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NewDeckDto {
+    pub name: String,
+    pub code: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampionMasteryDto {
     pub champion_points_until_next_level: i64,
     pub chest_granted: bool,
-    pub champion_id: i64,
+    pub champion_id: Champion,
     pub last_play_time: i64,
     pub champion_level: i32,
     pub summoner_id: String,
@@ -580,115 +1789,441 @@ pub struct ChampionMasteryDto {
     pub tokens_earned: i32
 }
 
-#[derive(Deserialize, Debug)]
+#[cfg(feature = "chrono")]
+impl ChampionMasteryDto {
+    /// [`last_play_time`](Self::last_play_time) as a datetime.
+    pub fn last_play_datetime(&self) -> DateTime<Utc> {
+        datetime(self.last_play_time)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampionInfo {
     pub max_new_player_level: i32,
-    pub free_champion_ids_for_new_players: Vec<i32>,
-    pub free_champion_ids: Vec<i32>
+    pub free_champion_ids_for_new_players: Vec<Champion>,
+    pub free_champion_ids: Vec<Champion>
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub enum MaintenanceStatus {
-    Secheduled,
-    InProgress,
-    Complete
+string_enum! {
+    pub enum MaintenanceStatus {
+        Secheduled => "scheduled",
+        InProgress => "in_progress",
+        Complete => "complete"
+    }
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub enum IncidentSeverity {
-    Info,
-    Warning,
-    Critical
+string_enum! {
+    pub enum IncidentSeverity {
+        Info => "info",
+        Warning => "warning",
+        Critical => "critical"
+    }
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub enum Platform {
-    Windows,
-    #[serde(rename = "macos")]
-    MacOS,
-    Android,
-    #[serde(rename = "ios")]
-    IOS,
-    Ps4,
-    Xbone,
-    Switch
+string_enum! {
+    pub enum Platform {
+        Windows => "windows",
+        MacOS => "macos",
+        Android => "android",
+        IOS => "ios",
+        Ps4 => "ps4",
+        Xbone => "xbone",
+        Switch => "switch"
+    }
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Lane {
-    None,
-    Top,
-    Jungle,
-    Middle,
-    Bottom
+string_enum! {
+    pub enum Lane {
+        None => "NONE",
+        Top => "TOP",
+        Jungle => "JUNGLE",
+        Middle => "MIDDLE",
+        Bottom => "BOTTOM"
+    }
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Role {
-    None,
-    Solo,
-    Carry,
-    Duo,
-    Support
+string_enum! {
+    pub enum Role {
+        None => "NONE",
+        Solo => "SOLO",
+        Carry => "CARRY",
+        Duo => "DUO",
+        Support => "SUPPORT"
+    }
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Position {
-    #[serde(rename = "")]
-    Unkown,
-    #[serde(rename = "Invalid")]
-    Invalid,
-    Top,
-    Jungle,
-    Middle,
-    Bottom,
-    Utility
-}
-
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum EventType {
-    AscendedEvent,
-    BuildingKill,
-    CapturePoint,
-    ChampionKill,
-    ChampionSpecialKill,
-    ChampionTransform,
-    DragonSoulGiven,
-    EliteMonsterKill,
-    GameEnd,
-    ItemDestroyed,
-    ItemPurchased,
-    ItemSold,
-    ItemUndo,
-    LevelUp,
-    PauseEnd,
-    PauseStart,
-    SkillLevelUp,
-    TurretPlateDestroyed,
-    WardKill,
-    WardPlaced
-}
-
-#[derive(Deserialize, Debug, PartialEq, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum Game {
-    LOR,
-    VAL
-}
-
-impl std::fmt::Display for Game {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self {
-            Game::LOR => write!(f, "lor"),
-            Game::VAL => write!(f, "val")
+string_enum! {
+    pub enum Position {
+        Unkown => "",
+        Invalid => "Invalid",
+        Top => "TOP",
+        Jungle => "JUNGLE",
+        Middle => "MIDDLE",
+        Bottom => "BOTTOM",
+        Utility => "UTILITY"
+    }
+}
+
+string_enum! {
+    pub enum EventType {
+        AscendedEvent => "ASCENDED_EVENT",
+        BuildingKill => "BUILDING_KILL",
+        CapturePoint => "CAPTURE_POINT",
+        ChampionKill => "CHAMPION_KILL",
+        ChampionSpecialKill => "CHAMPION_SPECIAL_KILL",
+        ChampionTransform => "CHAMPION_TRANSFORM",
+        DragonSoulGiven => "DRAGON_SOUL_GIVEN",
+        EliteMonsterKill => "ELITE_MONSTER_KILL",
+        FeatUpdate => "FEAT_UPDATE",
+        GameEnd => "GAME_END",
+        ItemDestroyed => "ITEM_DESTROYED",
+        ItemPurchased => "ITEM_PURCHASED",
+        ItemSold => "ITEM_SOLD",
+        ItemUndo => "ITEM_UNDO",
+        LevelUp => "LEVEL_UP",
+        ObjectiveBountyFinish => "OBJECTIVE_BOUNTY_FINISH",
+        ObjectiveBountyPrestart => "OBJECTIVE_BOUNTY_PRESTART",
+        PauseEnd => "PAUSE_END",
+        PauseStart => "PAUSE_START",
+        SkillLevelUp => "SKILL_LEVEL_UP",
+        TurretPlateDestroyed => "TURRET_PLATE_DESTROYED",
+        WardKill => "WARD_KILL",
+        WardPlaced => "WARD_PLACED"
+    }
+}
+
+string_enum! {
+    pub enum WardType {
+        BlueTrinket => "BLUE_TRINKET",
+        ControlWard => "CONTROL_WARD",
+        SightWard => "SIGHT_WARD",
+        TeemoMushroom => "TEEMO_MUSHROOM",
+        YellowTrinket => "YELLOW_TRINKET",
+        Undefined => "UNDEFINED"
+    }
+}
+
+string_enum! {
+    pub enum MonsterType {
+        Atakhan => "ATAKHAN",
+        BaronNashor => "BARON_NASHOR",
+        Dragon => "DRAGON",
+        Horde => "HORDE",
+        RiftHerald => "RIFTHERALD"
+    }
+}
+
+string_enum! {
+    pub enum MonsterSubType {
+        AirDragon => "AIR_DRAGON",
+        ChemtechDragon => "CHEMTECH_DRAGON",
+        EarthDragon => "EARTH_DRAGON",
+        ElderDragon => "ELDER_DRAGON",
+        FireDragon => "FIRE_DRAGON",
+        HextechDragon => "HEXTECH_DRAGON",
+        WaterDragon => "WATER_DRAGON"
+    }
+}
+
+string_enum! {
+    pub enum BuildingType {
+        InhibitorBuilding => "INHIBITOR_BUILDING",
+        TowerBuilding => "TOWER_BUILDING"
+    }
+}
+
+string_enum! {
+    pub enum TowerType {
+        OuterTurret => "OUTER_TURRET",
+        InnerTurret => "INNER_TURRET",
+        BaseTurret => "BASE_TURRET",
+        NexusTurret => "NEXUS_TURRET",
+        UndefinedTurret => "UNDEFINED_TURRET"
+    }
+}
+
+string_enum! {
+    pub enum LaneType {
+        TopLane => "TOP_LANE",
+        MidLane => "MID_LANE",
+        BotLane => "BOT_LANE"
+    }
+}
+
+string_enum! {
+    pub enum KillType {
+        KillAce => "KILL_ACE",
+        KillFirstBlood => "KILL_FIRST_BLOOD",
+        KillMulti => "KILL_MULTI"
+    }
+}
+
+string_enum! {
+    pub enum LevelUpType {
+        Normal => "NORMAL",
+        Evolve => "EVOLVE"
+    }
+}
+
+string_enum! {
+    pub enum TransformType {
+        Assassin => "ASSASSIN",
+        Slayer => "SLAYER"
+    }
+}
+
+string_enum! {
+    pub enum DragonSoul {
+        Chemtech => "Chemtech",
+        Cloud => "Cloud",
+        Hextech => "Hextech",
+        Infernal => "Infernal",
+        Mountain => "Mountain",
+        Ocean => "Ocean"
+    }
+}
+
+string_enum! {
+    pub enum Game {
+        LOR => "lor",
+        VAL => "val"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+    use serde_json::Value;
+
+    fn assert_round_trip<T>(json: &str)
+    where
+        T: DeserializeOwned + Serialize + Clone + PartialEq + std::fmt::Debug
+    {
+        let dto: T = serde_json::from_str(json).expect("Failed to deserialize");
+        let serialized = serde_json::to_value(dto.clone()).expect("Failed to serialize");
+        let original: Value = serde_json::from_str(json).expect("Invalid fixture");
+
+        assert_eq!(serialized, original);
+        assert_eq!(serde_json::from_value::<T>(serialized).expect("Failed to deserialize"), dto);
+    }
+
+    #[test]
+    fn match_round_trip() {
+        assert_round_trip::<MatchDto>(fixtures::MATCH_JSON);
+    }
+
+    #[test]
+    fn participant_champions() {
+        let match_ = fixtures::match_();
+
+        for participant in &match_.info.participants {
+            assert_eq!(participant.champion_id.name().as_deref(), Some(participant.champion_name.as_str()));
+        }
+        assert_eq!(match_.info.participants[2].champion_id, Champion::NEEKO);
+    }
+
+    #[test]
+    fn older_match_without_mode_specific_fields() {
+        let mut value: Value = serde_json::from_str(fixtures::MATCH_JSON).expect("Invalid fixture");
+        value["info"].as_object_mut().expect("Invalid fixture").remove("gameEndTimestamp");
+        let participant = value["info"]["participants"][0].as_object_mut().expect("Invalid fixture");
+        for field in ["challenges", "missions", "riotIdGameName", "placement", "playerAugment1", "allInPings"] {
+            participant.remove(field);
+        }
+        participant.insert("riotIdName".to_string(), Value::from("Påsan"));
+
+        let match_: MatchDto = serde_json::from_value(value).expect("Failed to deserialize");
+        let participant = &match_.info.participants[0];
+
+        assert_eq!(match_.info.game_end_timestamp, None);
+        assert_eq!(participant.challenges, None);
+        assert_eq!(participant.riot_id_name.as_deref(), Some("Påsan"));
+        assert_eq!(match_.info.participants[1].riot_id_game_name.as_deref(), Some("Blinded Monk"));
+    }
+
+    #[test]
+    fn game_length_before_and_after_11_20() {
+        let mut match_ = fixtures::match_();
+        assert_eq!(match_.info.game_length(), Duration::from_secs(1530));
+
+        match_.info.game_end_timestamp = None;
+        match_.info.game_duration = 1530412;
+        assert_eq!(match_.info.game_length(), Duration::from_millis(1530412));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn datetimes() {
+        let mut match_ = fixtures::match_();
+        let timeline = fixtures::timeline();
+        let game_end = timeline.info.frames.last().and_then(|frame| frame.events.last()).expect("No events");
+
+        assert_eq!(match_.info.game_start_time().to_rfc3339(), "2022-11-11T20:33:05+00:00");
+        assert_eq!(game_end.real_time(), Some(match_.info.game_end_time()));
+        assert_eq!(game_end.time(), Duration::from_millis(1530412));
+
+        // Before 11.20 the end is derived from the start and the duration in milliseconds
+        match_.info.game_end_timestamp = None;
+        match_.info.game_duration = 1530412;
+        assert_eq!(match_.info.game_end_time().timestamp_millis(), 1668198785000 + 1530412);
+    }
+
+    #[test]
+    fn match_timeline_round_trip() {
+        assert_round_trip::<MatchTimelineDto>(fixtures::TIMELINE_JSON);
+    }
+
+    #[test]
+    fn unknown_enum_values() {
+        let event_type: EventType = serde_json::from_str(r#""ATAKHAN_SPAWN""#).expect("Failed to deserialize");
+        assert_eq!(event_type, EventType::Unknown("ATAKHAN_SPAWN".to_string()));
+        assert_eq!(serde_json::to_string(&event_type).expect("Failed to serialize"), r#""ATAKHAN_SPAWN""#);
+
+        let position: Position = serde_json::from_str(r#""""#).expect("Failed to deserialize");
+        assert_eq!(position, Position::Unkown);
+
+        let event_type: EventType = serde_json::from_str(r#""FEAT_UPDATE""#).expect("Failed to deserialize");
+        assert_eq!(event_type, EventType::FeatUpdate);
+    }
+
+    #[test]
+    fn participant_frames_by_id_and_puuid() {
+        let timeline = fixtures::timeline();
+        let info = &timeline.info;
+        let puuid = &timeline.metadata.participants[2];
+
+        assert_eq!(info.frames[0].participant_frames.len(), 10);
+        assert_eq!(info.frames[5].participant_frames[3].participant_id, 3);
+        assert_eq!(info.participant_id(puuid), Some(3));
+        assert_eq!(info.participant_frames(puuid).count(), info.frames.len());
+        assert!(info.participant_frames(puuid).all(|frame| frame.participant_id == 3));
+        assert_eq!(info.participant_frames("unknown").count(), 0);
+    }
+
+    #[test]
+    fn typed_timeline_events() {
+        let timeline = fixtures::timeline();
+        let events: Vec<_> = timeline.info.frames.iter().flat_map(|frame| &frame.events).collect();
+
+        assert!(events.iter().all(|event| !matches!(event, MatchTimelineInfoFrameEvent::Unknown(_))));
+
+        let undo = events.iter().find_map(|event| match event {
+            MatchTimelineInfoFrameEvent::ItemUndo { participant_id, before_id, after_id, .. } => Some((*participant_id, *before_id, *after_id)),
+            _ => None
+        });
+        assert_eq!(undo, Some((3, 1001, 0)));
+
+        let first_kill = events.iter().find(|event| event.event_type() == EventType::ChampionKill).expect("No kills");
+        match first_kill {
+            MatchTimelineInfoFrameEvent::ChampionKill { killer_id, victim_id, victim_damage_received, .. } => {
+                assert_ne!(killer_id, victim_id);
+                assert!(!victim_damage_received.is_empty());
+            },
+            _ => unreachable!()
+        }
+
+        assert!(events.iter().any(|event| matches!(
+            event,
+            MatchTimelineInfoFrameEvent::EliteMonsterKill { monster_type: MonsterType::Dragon, monster_sub_type: Some(MonsterSubType::FireDragon), .. }
+        )));
+    }
+
+    #[test]
+    fn timeline_event_extra_fields() {
+        let json = r#"{"timestamp":1000,"type":"ITEM_PURCHASED","participantId":1,"itemId":1001,"teamId":100,"killerId":2}"#;
+        let event: MatchTimelineInfoFrameEvent = serde_json::from_str(json).expect("Failed to deserialize");
+        let extra = event.extra().expect("No extra fields");
+        assert_eq!(extra.get("teamId"), Some(&Value::from(100)));
+        assert_eq!(extra.get("killerId"), Some(&Value::from(2)));
+        assert_eq!(extra.len(), 2);
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize");
+        assert_eq!(serialized, serde_json::from_str::<Value>(json).expect("Invalid JSON"));
+    }
+
+    #[test]
+    fn unknown_and_incomplete_timeline_events() {
+        let json = r#"{"timestamp":1000,"type":"ATAKHAN_SPAWN","teamId":100}"#;
+        let event: MatchTimelineInfoFrameEvent = serde_json::from_str(json).expect("Failed to deserialize");
+        assert_eq!(event.timestamp(), 1000);
+        assert_eq!(event.event_type(), EventType::Unknown("ATAKHAN_SPAWN".to_string()));
+        assert_eq!(serde_json::to_string(&event).expect("Failed to serialize"), json);
+
+        let json = r#"{"timestamp":1000,"type":"ITEM_PURCHASED","participantId":1}"#;
+        let err = serde_json::from_str::<MatchTimelineInfoFrameEvent>(json).expect_err("Deserialized incomplete event");
+        assert!(err.to_string().contains("itemId"));
+    }
+
+    #[test]
+    fn lenient_incomplete_timeline_events() {
+        let mut value: Value = serde_json::from_str(fixtures::TIMELINE_JSON).expect("Invalid fixture");
+        let events = value["info"]["frames"][4]["events"].as_array_mut().expect("Invalid fixture");
+        let index = events.iter().position(|event| event["type"] == "CHAMPION_KILL").expect("No champion kill");
+        events[index].as_object_mut().expect("Invalid fixture").remove("position");
+
+        assert!(serde_json::from_value::<MatchTimelineDto>(value.clone()).is_err());
+
+        let timeline = lenient::from_value::<MatchTimelineDto>(value).expect("Failed to deserialize leniently");
+        let MatchTimelineInfoFrameEvent::ChampionKill { position, killer_id, .. } = &timeline.value.info.frames[4].events[index] else {
+            panic!("Not a champion kill");
+        };
+        assert_eq!((position.x, position.y, *killer_id), (0, 0, 1));
+        assert_eq!(timeline.drift.missing_fields, [format!("info.frames[4].events[{index}].position")]);
+    }
+
+    #[test]
+    fn arena_participant_frames() {
+        let mut value: Value = serde_json::from_str(fixtures::TIMELINE_JSON).expect("Invalid fixture");
+        for frame in value["info"]["frames"].as_array_mut().expect("Invalid fixture") {
+            let frames = frame["participantFrames"].as_object_mut().expect("Invalid fixture");
+            for participant_id in 11..=16 {
+                let mut participant_frame = frames["1"].clone();
+                participant_frame["participantId"] = Value::from(participant_id);
+                frames.insert(participant_id.to_string(), participant_frame);
+            }
         }
+
+        let timeline: MatchTimelineDto = serde_json::from_value(value).expect("Failed to deserialize");
+        let participant_frames = &timeline.info.frames[0].participant_frames;
+
+        assert_eq!(participant_frames.len(), 16);
+        assert_eq!(participant_frames.get(16).map(|frame| frame.participant_id), Some(16));
+        assert_eq!(participant_frames.iter().map(|frame| frame.participant_id).collect::<Vec<_>>(), (1..=16).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn summoner_round_trip() {
+        assert_round_trip::<SummonerDTO>(r#"{
+            "accountId": "a",
+            "profileIconId": 4568,
+            "revisionDate": 1668198754000,
+            "name": "Påsan",
+            "id": "-_Qcp4WDs8X7X_E62lgulgzHRHpNZ4vjk0TAYzMV9zCcWzY",
+            "puuid": "p",
+            "summonerLevel": 412
+        }"#);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn platform_data_round_trip() {
+        assert_round_trip::<PlatformDataDto>(r#"{
+            "id": "EUW1",
+            "name": "EU West",
+            "locales": ["en_GB"],
+            "maintenances": [],
+            "incidents": [{
+                "id": 1,
+                "maintenance_status": null,
+                "incident_severity": "warning",
+                "titles": [{"locale": "en_GB", "content": "Login issues"}],
+                "updates": [],
+                "created_at": "2022-11-11T18:00:00.000000+00:00",
+                "archive_at": null,
+                "updated_at": null,
+                "platforms": ["windows", "macos"]
+            }]
+        }"#);
+    }
+}
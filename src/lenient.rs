@@ -0,0 +1,692 @@
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+
+type Result<T> = std::result::Result<T, serde_json::Error>;
+
+/// Differences between a response and the DTO it was deserialized into.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDrift {
+    /// Paths of required fields that were absent or `null` and replaced by defaults.
+    pub missing_fields: Vec<String>,
+    /// Fields the DTO does not know about, keyed by path, with their values. DTOs have nowhere
+    /// to keep these, so this is the only place they are preserved.
+    pub unknown_fields: Map<String, Value>
+}
+
+impl SchemaDrift {
+    pub fn is_empty(&self) -> bool {
+        self.missing_fields.is_empty() && self.unknown_fields.is_empty()
+    }
+}
+
+/// A leniently deserialized value, next to the drift that was found, including the unknown
+/// fields the value could not hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lenient<T> {
+    pub value: T,
+    pub drift: SchemaDrift
+}
+
+/// Deserializes `json` into `T`, filling missing fields with their defaults (`0`, `false`,
+/// `""`, empty collections, `None`) and collecting unrecognized fields instead of failing.
+pub fn from_slice<T>(json: &[u8]) -> Result<Lenient<T>>
+where
+    T: DeserializeOwned
+{
+    from_value(serde_json::from_slice(json)?)
+}
+
+pub fn from_str<T>(json: &str) -> Result<Lenient<T>>
+where
+    T: DeserializeOwned
+{
+    from_value(serde_json::from_str(json)?)
+}
+
+pub fn from_value<T>(value: Value) -> Result<Lenient<T>>
+where
+    T: DeserializeOwned
+{
+    let drift = RefCell::new(SchemaDrift::default());
+    let context = Context::enter();
+    let value = T::deserialize(LenientDeserializer {
+        value,
+        path: String::new(),
+        drift: &drift
+    })?;
+
+    let mut drift = drift.into_inner();
+    drift.missing_fields.extend(context.exit());
+    Ok(Lenient { value, drift })
+}
+
+/// State of the lenient deserialization running on the current thread, for conversions that
+/// run outside the deserializer, like `#[serde(try_from)]`.
+#[derive(Default)]
+struct Context {
+    /// Paths of the values being deserialized, innermost last.
+    paths: Vec<String>,
+    missing_fields: Vec<String>
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+/// Resets the thread's context when dropped, even if deserialization panics.
+struct ContextGuard {
+    previous: Option<Context>
+}
+
+impl Context {
+    fn enter() -> ContextGuard {
+        ContextGuard { previous: CONTEXT.with(|context| context.replace(Some(Context::default()))) }
+    }
+
+    fn scoped<T>(path: &str, f: impl FnOnce() -> T) -> T {
+        CONTEXT.with(|context| {
+            if let Some(context) = context.borrow_mut().as_mut() {
+                context.paths.push(path.to_string());
+            }
+        });
+        let result = f();
+        CONTEXT.with(|context| {
+            if let Some(context) = context.borrow_mut().as_mut() {
+                context.paths.pop();
+            }
+        });
+        result
+    }
+}
+
+impl ContextGuard {
+    /// Missing fields recorded outside the deserializer.
+    fn exit(self) -> Vec<String> {
+        CONTEXT.with(|context| {
+            context
+                .borrow_mut()
+                .as_mut()
+                .map(|context| std::mem::take(&mut context.missing_fields))
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| context.replace(self.previous.take()));
+    }
+}
+
+/// The default for a required `field` of the value being converted, if the current thread is
+/// deserializing leniently. The field is then recorded as missing. Outside lenient mode this
+/// returns `None`, so the caller can fail as usual.
+pub(crate) fn missing_field<T>(field: &str) -> Option<T>
+where
+    T: DeserializeOwned
+{
+    let path = CONTEXT.with(|context| {
+        let mut context = context.borrow_mut();
+        let context = context.as_mut()?;
+        let path = join(context.paths.last().map_or("", String::as_str), field);
+        context.missing_fields.push(path.clone());
+        Some(path)
+    })?;
+
+    let drift = RefCell::new(SchemaDrift::default());
+    T::deserialize(DefaultDeserializer { path, drift: &drift, report: false }).ok()
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+struct LenientDeserializer<'a> {
+    value: Value,
+    path: String,
+    drift: &'a RefCell<SchemaDrift>
+}
+
+impl<'a> LenientDeserializer<'a> {
+    fn missing(self) -> DefaultDeserializer<'a> {
+        DefaultDeserializer { path: self.path, drift: self.drift, report: true }
+    }
+}
+
+macro_rules! lenient_primitive {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value>
+            where
+                V: Visitor<'de>
+            {
+                match self.value {
+                    Value::Null => self.missing().$method(visitor),
+                    value => value.$method(visitor)
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> de::Deserializer<'de> for LenientDeserializer<'a> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        let LenientDeserializer { value, path, drift } = self;
+
+        match value {
+            Value::Array(array) => visitor.visit_seq(LenientSeq {
+                values: array.into_iter().enumerate(),
+                path,
+                drift
+            }),
+            Value::Object(object) => visitor.visit_map(LenientMap {
+                entries: object.into_iter().collect::<Vec<_>>().into_iter(),
+                missing: Vec::new().into_iter(),
+                value: None,
+                path,
+                drift
+            }),
+            value => value.deserialize_any(visitor)
+        }
+    }
+
+    lenient_primitive! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_f32 deserialize_f64
+        deserialize_char deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_unit deserialize_identifier
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        match self.value {
+            Value::Array(_) => self.deserialize_any(visitor),
+            Value::Null => self.missing().deserialize_seq(visitor),
+            value => value.deserialize_seq(visitor)
+        }
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(self, _name: &'static str, _len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        match self.value {
+            Value::Object(_) => self.deserialize_any(visitor),
+            Value::Null => self.missing().deserialize_map(visitor),
+            value => value.deserialize_map(visitor)
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        let object = match self.value {
+            Value::Object(object) => object,
+            Value::Null => return self.missing().deserialize_struct(name, fields, visitor),
+            value => return value.deserialize_struct(name, fields, visitor)
+        };
+        let LenientDeserializer { path, drift, .. } = self;
+
+        let mut entries = Vec::new();
+        for (key, value) in object {
+            if fields.contains(&key.as_str()) {
+                entries.push((key, value));
+            } else {
+                drift.borrow_mut().unknown_fields.insert(join(&path, &key), value);
+            }
+        }
+
+        let missing: Vec<&'static str> = fields
+            .iter()
+            .filter(|field| !entries.iter().any(|(key, _)| key == *field))
+            .copied()
+            .collect();
+
+        visitor.visit_map(LenientMap {
+            entries: entries.into_iter(),
+            missing: missing.into_iter(),
+            value: None,
+            path,
+            drift
+        })
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct LenientSeq<'a> {
+    values: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+    path: String,
+    drift: &'a RefCell<SchemaDrift>
+}
+
+impl<'de, 'a> de::SeqAccess<'de> for LenientSeq<'a> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>>
+    where
+        S: DeserializeSeed<'de>
+    {
+        match self.values.next() {
+            Some((index, value)) => {
+                let path = format!("{}[{index}]", self.path);
+                Context::scoped(&path, || seed.deserialize(LenientDeserializer { value, path: path.clone(), drift: self.drift }))
+                    .map(Some)
+            },
+            None => Ok(None)
+        }
+    }
+}
+
+enum PendingValue {
+    Present(String, Value),
+    Missing(&'static str)
+}
+
+struct LenientMap<'a> {
+    entries: std::vec::IntoIter<(String, Value)>,
+    missing: std::vec::IntoIter<&'static str>,
+    value: Option<PendingValue>,
+    path: String,
+    drift: &'a RefCell<SchemaDrift>
+}
+
+impl<'de, 'a> de::MapAccess<'de> for LenientMap<'a> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>
+    {
+        if let Some((key, value)) = self.entries.next() {
+            let result = seed.deserialize(KeyDeserializer(key.clone()))?;
+            self.value = Some(PendingValue::Present(key, value));
+            return Ok(Some(result));
+        }
+
+        if let Some(field) = self.missing.next() {
+            let result = seed.deserialize(KeyDeserializer(field.to_string()))?;
+            self.value = Some(PendingValue::Missing(field));
+            return Ok(Some(result));
+        }
+
+        Ok(None)
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value>
+    where
+        S: DeserializeSeed<'de>
+    {
+        match self.value.take() {
+            Some(PendingValue::Present(key, value)) => {
+                let path = join(&self.path, &key);
+                Context::scoped(&path, || seed.deserialize(LenientDeserializer { value, path: path.clone(), drift: self.drift }))
+            },
+            Some(PendingValue::Missing(field)) => seed.deserialize(DefaultDeserializer {
+                path: join(&self.path, field),
+                drift: self.drift,
+                report: true
+            }),
+            None => Err(de::Error::custom("value is missing"))
+        }
+    }
+}
+
+/// Map keys arrive as strings, but may stand for integers, e.g. participant ids.
+struct KeyDeserializer(String);
+
+macro_rules! parse_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value>
+            where
+                V: Visitor<'de>
+            {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => visitor.visit_string(self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        visitor.visit_string(self.0)
+    }
+
+    parse_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.0.into_deserializer().deserialize_enum(name, variants, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf option unit unit_struct newtype_struct seq
+        tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Produces the default value for whatever type asks for it, recording the field as missing.
+struct DefaultDeserializer<'a> {
+    path: String,
+    drift: &'a RefCell<SchemaDrift>,
+    report: bool
+}
+
+impl<'a> DefaultDeserializer<'a> {
+    fn record(&self) {
+        if self.report {
+            self.drift.borrow_mut().missing_fields.push(self.path.clone());
+        }
+    }
+}
+
+macro_rules! default_primitive {
+    ($($method:ident => $visit:ident($($value:expr)?),)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value>
+            where
+                V: Visitor<'de>
+            {
+                self.record();
+                visitor.$visit($($value)?)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> de::Deserializer<'de> for DefaultDeserializer<'a> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.record();
+        visitor.visit_unit()
+    }
+
+    default_primitive! {
+        deserialize_bool => visit_bool(false),
+        deserialize_i8 => visit_i8(0),
+        deserialize_i16 => visit_i16(0),
+        deserialize_i32 => visit_i32(0),
+        deserialize_i64 => visit_i64(0),
+        deserialize_u8 => visit_u8(0),
+        deserialize_u16 => visit_u16(0),
+        deserialize_u32 => visit_u32(0),
+        deserialize_u64 => visit_u64(0),
+        deserialize_f32 => visit_f32(0.0),
+        deserialize_f64 => visit_f64(0.0),
+        deserialize_char => visit_char('\0'),
+        deserialize_str => visit_str(""),
+        deserialize_string => visit_str(""),
+        deserialize_bytes => visit_bytes(&[]),
+        deserialize_byte_buf => visit_bytes(&[]),
+        deserialize_unit => visit_unit(),
+        deserialize_identifier => visit_str(""),
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        visitor.visit_none()
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.record();
+        visitor.visit_seq(de::value::SeqDeserializer::<_, serde_json::Error>::new(std::iter::empty::<Value>()))
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(self, _name: &'static str, _len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.record();
+        visitor.visit_map(de::value::MapDeserializer::<_, serde_json::Error>::new(std::iter::empty::<(Value, Value)>()))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        self.record();
+        visitor.visit_map(DefaultStruct {
+            fields: fields.iter(),
+            drift: self.drift
+        })
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        _visitor: V
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        Err(de::Error::custom(format_args!("missing field `{}` has no default", self.path)))
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct DefaultStruct<'a> {
+    fields: std::slice::Iter<'static, &'static str>,
+    drift: &'a RefCell<SchemaDrift>
+}
+
+impl<'de, 'a> de::MapAccess<'de> for DefaultStruct<'a> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>
+    {
+        match self.fields.next() {
+            Some(field) => seed.deserialize(KeyDeserializer(field.to_string())).map(Some),
+            None => Ok(None)
+        }
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value>
+    where
+        S: DeserializeSeed<'de>
+    {
+        seed.deserialize(DefaultDeserializer {
+            path: String::new(),
+            drift: self.drift,
+            report: false
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+    use crate::endpoints::constants::{MatchDto, SummonerDTO};
+
+    #[test]
+    fn missing_and_unknown_fields() {
+        let summoner = from_str::<SummonerDTO>(r#"{
+            "accountId": "a",
+            "profileIconId": 4568,
+            "name": "Påsan",
+            "id": "i",
+            "puuid": "p",
+            "summonerLevel": null,
+            "tagLine": "Neeko"
+        }"#).expect("Failed to deserialize leniently");
+
+        assert_eq!(summoner.value.revision_date, 0);
+        assert_eq!(summoner.value.summoner_level, 0);
+        assert_eq!(summoner.value.name, "Påsan");
+        assert_eq!(summoner.drift.missing_fields, vec!["summonerLevel", "revisionDate"]);
+        assert_eq!(summoner.drift.unknown_fields["tagLine"], "Neeko");
+    }
+
+    #[test]
+    fn renamed_participant_field() {
+        let mut value: Value = serde_json::from_str(fixtures::MATCH_JSON).expect("Invalid fixture");
+        let participant = value["info"]["participants"][3].as_object_mut().expect("Invalid fixture");
+        participant.remove("summonerName");
+        participant.insert("riotIdDisplayName".to_string(), Value::from("Get Excited"));
+
+        assert!(serde_json::from_value::<MatchDto>(value.clone()).is_err());
+
+        let match_ = from_value::<MatchDto>(value).expect("Failed to deserialize leniently");
+        assert_eq!(match_.value.info.participants[3].summoner_name, "");
+        assert_eq!(match_.drift.missing_fields, vec!["info.participants[3].summonerName"]);
+        assert_eq!(match_.drift.unknown_fields["info.participants[3].riotIdDisplayName"], "Get Excited");
+    }
+
+    #[test]
+    fn matching_schema_has_no_drift() {
+        let timeline = from_str::<crate::endpoints::constants::MatchTimelineDto>(
+            fixtures::TIMELINE_JSON
+        ).expect("Failed to deserialize leniently");
+
+        assert!(timeline.drift.is_empty());
+    }
+}
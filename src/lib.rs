@@ -1,11 +1,30 @@
+#[macro_use]
+mod macros;
+
+pub mod analytics;
+pub mod catalog;
+#[cfg(feature = "cdragon")]
+pub mod cdragon;
+pub mod champion;
 pub mod client;
+pub mod ddragon;
 pub mod endpoints;
+pub mod lenient;
+pub mod rso;
+#[cfg(feature = "sqlite")]
+pub mod store;
+pub mod version;
+
+#[cfg(test)]
+mod test_utils;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::{error, env};
+    use crate::client::constants::EndpointGroup;
     use crate::endpoints::constants::Game;
+    use crate::test_utils::{MockResponse, MockServer};
 
     type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
 
@@ -129,6 +148,228 @@ mod tests {
         assert_eq!(account.game_name, Some(game_name));
     }
 
+    #[tokio::test]
+    async fn get_account_me() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(200, r#"{"puuid": "abc", "gameName": "Påsan", "tagLine": "Neeko"}"#)
+                .with_header("X-App-Rate-Limit", "20:1,100:120")
+                .with_header("X-App-Rate-Limit-Count", "1:1,1:120")
+        }).await;
+        let client = create_client(Some("key".to_string()))
+            .expect("Failed to create client.")
+            .with_base_url(server.url());
+
+        let account = client.get_account_me("token").await.expect("Failed to get account info");
+
+        assert_eq!(account.puuid, "abc");
+        assert_eq!(client.rate_limits(EndpointGroup::Account)[&server.url()][0].count, 1);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/riot/account/v1/accounts/me");
+        assert_eq!(requests[0].header("authorization"), Some("Bearer token"));
+        assert_eq!(requests[0].header("x-riot-token"), None);
+    }
+
+    #[tokio::test]
+    async fn create_lor_deck_me() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(200, r#""deck-id""#)
+                .with_header("X-App-Rate-Limit", "20:1,100:120")
+                .with_header("X-App-Rate-Limit-Count", "1:1,1:120")
+        }).await;
+        let client = create_client(Some("key".to_string()))
+            .expect("Failed to create client.")
+            .with_base_url(server.url());
+
+        let deck = endpoints::constants::NewDeckDto { name: "Neeko".to_string(), code: "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCBIFAEAQCBAA".to_string() };
+        let id = client.create_lor_deck_me("token", &deck).await.expect("Failed to create deck");
+
+        assert_eq!(id, "deck-id");
+        assert_eq!(client.rate_limits(EndpointGroup::LorDeck)[&server.url()][0].count, 1);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/lor/deck/v1/decks/me");
+        assert_eq!(requests[0].header("authorization"), Some("Bearer token"));
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&requests[0].body).expect("Invalid body"), serde_json::json!({"name": "Neeko", "code": deck.code}));
+    }
+
+    #[tokio::test]
+    async fn api_keys_per_endpoint_group() {
+        let server = MockServer::start(|request| {
+            let body = match request.path.as_str() {
+                "/lol/platform/v3/champion-rotations" => r#"{"maxNewPlayerLevel": 10, "freeChampionIdsForNewPlayers": [], "freeChampionIds": []}"#,
+                _ => r#"{"puuid": "abc"}"#
+            };
+            MockResponse::json(200, body)
+                .with_header("X-App-Rate-Limit", "20:1,100:120")
+                .with_header("X-App-Rate-Limit-Count", "1:1,1:120")
+        }).await;
+        let client = create_client(Some("default".to_string()))
+            .expect("Failed to create client.")
+            .with_api_key("champion".to_string(), &[EndpointGroup::Champion])
+            .with_base_url(server.url());
+
+        client.get_champion_rotation().await.expect("Failed to get champion rotation");
+        client.get_account_by_puuid("abc".to_string()).await.expect("Failed to get account info");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("x-riot-token"), Some("champion"));
+        assert_eq!(requests[1].header("x-riot-token"), Some("default"));
+
+        let champion_limits = client.rate_limits(EndpointGroup::Champion);
+        let account_limits = client.rate_limits(EndpointGroup::Account);
+        assert_eq!(champion_limits[&server.url()].len(), 2);
+        assert_eq!(champion_limits[&server.url()][0].count, 1);
+        assert_eq!(account_limits[&server.url()][1].limit, 100);
+        assert!(client.rate_limits(EndpointGroup::Match).contains_key(&server.url()));
+    }
+
+    #[tokio::test]
+    async fn refresh_rejected_api_key() {
+        let server = MockServer::start(|request| {
+            match request.header("x-riot-token") {
+                Some("fresh") => MockResponse::json(200, r#"{"puuid": "abc"}"#),
+                _ => MockResponse::json(401, r#"{"status": {"message": "Unauthorized", "status_code": 401}}"#)
+            }
+        }).await;
+        let client = create_client(Some("expired".to_string()))
+            .expect("Failed to create client.")
+            .with_api_key_provider(|group: Option<EndpointGroup>, rejected: String| async move {
+                assert_eq!(group, Some(EndpointGroup::Account));
+                assert_ne!(rejected, "fresh");
+                Some("fresh".to_string())
+            })
+            .with_base_url(server.url());
+
+        let account = client.get_account_by_puuid("abc".to_string()).await.expect("Failed to get account info");
+        assert_eq!(account.puuid, "abc");
+        assert_eq!(server.requests().len(), 2);
+
+        client.set_api_key("revoked".to_string());
+        client.get_account_by_puuid("abc".to_string()).await.expect("Failed to get account info");
+        assert_eq!(server.requests()[3].header("x-riot-token"), Some("fresh"));
+    }
+
+    #[tokio::test]
+    async fn set_api_key() {
+        let server = MockServer::start(|_| MockResponse::json(200, r#"{"puuid": "abc"}"#)).await;
+        let client = create_client(Some("old".to_string()))
+            .expect("Failed to create client.")
+            .with_base_url(server.url());
+
+        client.set_api_key("new".to_string());
+        client.get_account_by_puuid("abc".to_string()).await.expect("Failed to get account info");
+
+        assert_eq!(server.requests()[0].header("x-riot-token"), Some("new"));
+
+        client.set_group_api_key(EndpointGroup::Account, "account".to_string());
+        client.get_account_by_puuid("abc".to_string()).await.expect("Failed to get account info");
+        client.request::<serde_json::Value>(reqwest::Method::GET, "/".to_string(), false, None).await.expect("Failed to request");
+
+        assert_eq!(server.requests()[1].header("x-riot-token"), Some("account"));
+        assert_eq!(server.requests()[2].header("x-riot-token"), Some("new"));
+    }
+
+    #[tokio::test]
+    async fn set_group_api_key_of_shared_key() {
+        let server = MockServer::start(|_| MockResponse::json(200, r#"{"puuid": "abc"}"#)).await;
+        let client = create_client(Some("default".to_string()))
+            .expect("Failed to create client.")
+            .with_api_key("shared".to_string(), &[EndpointGroup::Account, EndpointGroup::Summoner])
+            .with_base_url(server.url());
+
+        client.set_group_api_key(EndpointGroup::Account, "account".to_string());
+        client.get_account_by_puuid("abc".to_string()).await.expect("Failed to get account info");
+        client
+            .request_with_group::<serde_json::Value>(EndpointGroup::Summoner, reqwest::Method::GET, "/".to_string(), false, None)
+            .await
+            .expect("Failed to request");
+
+        assert_eq!(server.requests()[0].header("x-riot-token"), Some("account"));
+        assert_eq!(server.requests()[1].header("x-riot-token"), Some("shared"));
+    }
+
+    #[tokio::test]
+    async fn lenient_deserialization() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(200, r#"{"puuid": "abc", "gameName": "Påsan", "tagLine": "Neeko", "region": "euw"}"#)
+        }).await;
+        let drift = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let reported = drift.clone();
+        let client = create_client(Some("key".to_string()))
+            .expect("Failed to create client.")
+            .with_lenient_deserialization(move |endpoint, drift| {
+                reported.lock().unwrap().push((endpoint.to_string(), drift.clone()));
+            })
+            .with_base_url(server.url());
+
+        let summoner = client.get_summoner_by_puuid("abc").await.expect("Failed to get summoner");
+        assert_eq!(summoner.puuid, "abc");
+        assert_eq!(summoner.summoner_level, 0);
+
+        let drift = drift.lock().unwrap();
+        assert_eq!(drift[0].0, "/lol/summoner/v4/summoners/by-puuid/abc");
+        assert_eq!(drift[0].1.missing_fields.len(), 6);
+        assert_eq!(drift[0].1.unknown_fields.len(), 3);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn get_match_from_store() {
+        let server = MockServer::start(|_| MockResponse::json(200, crate::test_utils::fixtures::MATCH_JSON)).await;
+        let store = std::sync::Arc::new(store::MatchStore::open_in_memory().expect("Failed to open store"));
+        let client = create_client(Some("key".to_string()))
+            .expect("Failed to create client.")
+            .with_store(store.clone())
+            .with_base_url(server.url());
+
+        let match_id = "EUW1_6151255544".to_string();
+        let fetched = client.get_match(match_id.clone()).await.expect("Failed to get match");
+        let stored = client.get_match(match_id.clone()).await.expect("Failed to get match");
+
+        assert_eq!(fetched, stored);
+        assert_eq!(server.requests().len(), 1);
+        assert!(store.contains_match(&match_id).expect("Failed to query store"));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn get_match_with_broken_store() {
+        use crate::test_utils::fixtures;
+        use std::sync::{Arc, Mutex};
+
+        let server = MockServer::start(|request| match request.path.ends_with("/timeline") {
+            true => MockResponse::json(200, fixtures::TIMELINE_JSON),
+            false => MockResponse::json(200, fixtures::MATCH_JSON)
+        }).await;
+        let path = env::temp_dir().join(format!("neeko-broken-store-{}.sqlite", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let store = Arc::new(store::MatchStore::open(&path).expect("Failed to open store"));
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let client = create_client(Some("key".to_string()))
+            .expect("Failed to create client.")
+            .with_store(store.clone())
+            .with_store_error_handler({
+                let errors = errors.clone();
+                move |match_id, _| errors.lock().unwrap().push(match_id.to_string())
+            })
+            .with_base_url(server.url());
+
+        let match_id = "EUW1_6151255544".to_string();
+        store.save_match(&fixtures::match_()).expect("Failed to save match");
+        let connection = rusqlite::Connection::open(&path).expect("Failed to open database");
+        connection.execute_batch("UPDATE matches SET data = '{}'; DROP TABLE timelines;").expect("Failed to break store");
+
+        let match_ = client.get_match(match_id.clone()).await.expect("Failed to get match");
+        assert_eq!(store.get_match(&match_id).expect("Failed to query store"), Some(match_));
+        client.get_match_timeline(match_id.clone()).await.expect("Failed to get match timeline");
+
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(*errors.lock().unwrap(), [match_id.clone(), match_id.clone(), match_id]);
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[tokio::test]
     async fn get_active_shard() {
         let developer_api_key = env::var("riot_api_key_developer").expect("Failed to get developer api_key");
@@ -160,14 +401,14 @@ mod tests {
         let client = create_client(None).expect("Failed to create client.");
 
         let encrypted_summoner_id = "-_Qcp4WDs8X7X_E62lgulgzHRHpNZ4vjk0TAYzMV9zCcWzY".to_string();
-        let champion_id = 518;
+        let champion = champion::Champion::NEEKO;
         let champion_mastery = client
-            .get_champion_mastery_by_champion_id(encrypted_summoner_id.clone(), champion_id)
+            .get_champion_mastery_by_champion_id(encrypted_summoner_id.clone(), champion)
             .await
             .expect("Failed to get champion mastery");
 
         assert_eq!(champion_mastery.summoner_id, encrypted_summoner_id);
-        assert_eq!(champion_mastery.champion_id, champion_id);
+        assert_eq!(champion_mastery.champion_id, champion);
     }
 
     #[tokio::test]
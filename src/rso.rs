@@ -0,0 +1,170 @@
+pub mod constants;
+use self::constants::{RsoError, TokenResponse};
+use std::error::Error;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const RSO_BASE_URL: &str = "https://auth.riotgames.com";
+
+/// OAuth client for Riot Sign-On, used to obtain the player access tokens required by
+/// the `*/me` endpoints.
+pub struct RsoClient {
+    client: reqwest::Client,
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    base_url: String
+}
+
+impl RsoClient {
+    pub fn new(
+        client_id: String,
+        client_secret: String,
+        redirect_uri: String
+    ) -> std::result::Result<Self, reqwest::Error> {
+        let client = reqwest::ClientBuilder::new()
+            .build()?;
+
+        Ok(RsoClient {
+            client,
+            client_id,
+            client_secret,
+            redirect_uri,
+            base_url: RSO_BASE_URL.to_string()
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// URL the player should be sent to in order to sign in and authorize the application.
+    pub fn authorization_url(
+        &self,
+        scopes: &[&str],
+        state: Option<&str>
+    ) -> Result<String> {
+        let mut params = vec![
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("client_id", self.client_id.as_str()),
+            ("response_type", "code")
+        ];
+
+        let scope = scopes.join(" ");
+        params.push(("scope", &scope));
+
+        if let Some(state) = state {
+            params.push(("state", state));
+        }
+
+        let url = reqwest::Url::parse_with_params(&format!("{}/authorize", self.base_url), &params)?;
+        Ok(url.to_string())
+    }
+
+    pub async fn exchange_code(
+        &self,
+        code: &str
+    ) -> Result<TokenResponse> {
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.redirect_uri)
+        ];
+        self.token_request(&form).await
+    }
+
+    pub async fn refresh_token(
+        &self,
+        refresh_token: &str
+    ) -> Result<TokenResponse> {
+        let form = [
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token)
+        ];
+        self.token_request(&form).await
+    }
+
+    async fn token_request(
+        &self,
+        form: &[(&str, &str)]
+    ) -> Result<TokenResponse> {
+        let resp = self
+            .client
+            .post(format!("{}/token", self.base_url))
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .form(form)
+            .send()
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            _ if status.is_success() => Ok(resp.json().await?),
+            _ => Err(Box::new(resp.json::<RsoError>().await?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{MockResponse, MockServer};
+
+    const TOKEN_BODY: &str = r#"{
+        "access_token": "access",
+        "refresh_token": "refresh",
+        "id_token": "id",
+        "scope": "openid offline_access",
+        "token_type": "Bearer",
+        "expires_in": 3600
+    }"#;
+
+    fn rso_client(base_url: String) -> RsoClient {
+        RsoClient::new("neeko".to_string(), "secret".to_string(), "http://localhost/callback".to_string())
+            .expect("Failed to create RSO client.")
+            .with_base_url(base_url)
+    }
+
+    #[test]
+    fn authorization_url() {
+        let client = rso_client("http://auth.local".to_string());
+        let url = client.authorization_url(&["openid", "offline_access"], Some("xyz")).expect("Failed to build url");
+
+        assert_eq!(
+            url,
+            "http://auth.local/authorize?redirect_uri=http%3A%2F%2Flocalhost%2Fcallback&client_id=neeko&response_type=code&scope=openid+offline_access&state=xyz"
+        );
+    }
+
+    #[tokio::test]
+    async fn exchange_and_refresh() {
+        let server = MockServer::start(|_| MockResponse::json(200, TOKEN_BODY)).await;
+        let client = rso_client(server.url());
+
+        let token = client.exchange_code("abc").await.expect("Failed to exchange code");
+        assert_eq!(token.access_token, "access");
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh"));
+
+        client.refresh_token("refresh").await.expect("Failed to refresh token");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/token");
+        assert_eq!(requests[0].header("authorization"), Some("Basic bmVla286c2VjcmV0"));
+        assert!(requests[0].body.contains("grant_type=authorization_code"));
+        assert!(requests[0].body.contains("code=abc"));
+        assert!(requests[1].body.contains("grant_type=refresh_token"));
+    }
+
+    #[tokio::test]
+    async fn token_error() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(400, r#"{"error": "invalid_grant", "error_description": "code expired"}"#)
+        }).await;
+        let client = rso_client(server.url());
+
+        let err = client.exchange_code("abc").await.expect_err("Expected invalid grant");
+        assert_eq!(err.to_string(), "RSO error: invalid_grant, Description: 'code expired'");
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RsoError {
+    pub error: String,
+    pub error_description: Option<String>
+}
+
+impl std::fmt::Display for RsoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.error_description {
+            Some(description) => write!(f, "RSO error: {}, Description: '{}'", self.error, description),
+            None => write!(f, "RSO error: {}", self.error)
+        }
+    }
+}
+
+impl std::error::Error for RsoError {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub id_token: Option<String>,
+    pub scope: String,
+    pub token_type: String,
+    pub expires_in: i64
+}
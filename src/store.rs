@@ -0,0 +1,395 @@
+use crate::catalog::Queue;
+use crate::champion::Champion;
+use crate::endpoints::constants::{AccountDto, MatchDto, MatchTimelineDto, SummonerDTO};
+use crate::version::Patch;
+use rusqlite::{params, types::Value, Connection, OptionalExtension};
+use serde::de::DeserializeOwned;
+use std::{error::Error, path::Path, sync::Mutex};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS matches (
+    match_id TEXT PRIMARY KEY,
+    queue_id INTEGER NOT NULL,
+    patch_major INTEGER,
+    patch_minor INTEGER,
+    game_creation INTEGER NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS matches_queue ON matches (queue_id, game_creation);
+CREATE INDEX IF NOT EXISTS matches_patch ON matches (patch_major, patch_minor);
+CREATE INDEX IF NOT EXISTS matches_game_creation ON matches (game_creation);
+
+CREATE TABLE IF NOT EXISTS match_participants (
+    match_id TEXT NOT NULL REFERENCES matches (match_id) ON DELETE CASCADE,
+    participant_id INTEGER NOT NULL,
+    puuid TEXT NOT NULL,
+    champion_id INTEGER NOT NULL,
+    team_id INTEGER NOT NULL,
+    win INTEGER NOT NULL,
+    PRIMARY KEY (match_id, participant_id)
+);
+CREATE INDEX IF NOT EXISTS match_participants_puuid ON match_participants (puuid);
+CREATE INDEX IF NOT EXISTS match_participants_champion ON match_participants (champion_id);
+
+CREATE TABLE IF NOT EXISTS timelines (
+    match_id TEXT PRIMARY KEY,
+    data TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS summoners (
+    puuid TEXT PRIMARY KEY,
+    summoner_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS summoners_summoner_id ON summoners (summoner_id);
+
+CREATE TABLE IF NOT EXISTS accounts (
+    puuid TEXT PRIMARY KEY,
+    game_name TEXT,
+    tag_line TEXT,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS accounts_riot_id ON accounts (game_name, tag_line);
+";
+
+/// Filters for [`MatchStore::matches`]. Every filter that is set has to match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchQuery {
+    puuid: Option<String>,
+    champion: Option<Champion>,
+    queue: Option<Queue>,
+    patch: Option<Patch>,
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    count: Option<u32>
+}
+
+impl MatchQuery {
+    pub fn new() -> Self {
+        MatchQuery::default()
+    }
+
+    pub fn with_puuid(mut self, puuid: impl Into<String>) -> Self {
+        self.puuid = Some(puuid.into());
+        self
+    }
+
+    /// Matches where the champion was played, by the player if [`with_puuid`](Self::with_puuid)
+    /// is set.
+    pub fn with_champion(mut self, champion: Champion) -> Self {
+        self.champion = Some(champion);
+        self
+    }
+
+    pub fn with_queue(mut self, queue: Queue) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
+    pub fn with_patch(mut self, patch: Patch) -> Self {
+        self.patch = Some(patch);
+        self
+    }
+
+    /// Matches created at or after the epoch timestamp in milliseconds, like `InfoDto::game_creation`.
+    pub fn with_start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Matches created before the epoch timestamp in milliseconds.
+    pub fn with_end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    fn to_sql(&self, columns: &str) -> (String, Vec<Value>) {
+        let mut sql = format!("SELECT {columns} FROM matches m");
+        let mut conditions = Vec::new();
+        let mut values = Vec::new();
+
+        if self.puuid.is_some() || self.champion.is_some() {
+            sql.push_str(" JOIN match_participants p ON p.match_id = m.match_id");
+        }
+        if let Some(puuid) = &self.puuid {
+            conditions.push("p.puuid = ?");
+            values.push(Value::Text(puuid.clone()));
+        }
+        if let Some(champion) = self.champion {
+            conditions.push("p.champion_id = ?");
+            values.push(Value::Integer(champion.key()));
+        }
+        if let Some(queue) = self.queue {
+            conditions.push("m.queue_id = ?");
+            values.push(Value::Integer(queue.id()));
+        }
+        if let Some(patch) = self.patch {
+            conditions.push("m.patch_major = ? AND m.patch_minor = ?");
+            values.push(Value::Integer(patch.major.into()));
+            values.push(Value::Integer(patch.minor.into()));
+        }
+        if let Some(start_time) = self.start_time {
+            conditions.push("m.game_creation >= ?");
+            values.push(Value::Integer(start_time));
+        }
+        if let Some(end_time) = self.end_time {
+            conditions.push("m.game_creation < ?");
+            values.push(Value::Integer(end_time));
+        }
+
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        // Without a player, a champion filter can match several participants of a match.
+        sql.push_str(" GROUP BY m.match_id ORDER BY m.game_creation DESC");
+        if let Some(count) = self.count {
+            sql.push_str(&format!(" LIMIT {count}"));
+        }
+        (sql, values)
+    }
+}
+
+/// Persists matches, timelines, summoners and accounts in SQLite, so crawlers do not have to
+/// download them again. Saving is an upsert, so saving the same match twice is harmless. Whole
+/// objects are stored as JSON next to the indexed columns.
+///
+/// Calls block on SQLite, which is fast enough for a local database but worth keeping in mind
+/// in async code.
+pub struct MatchStore {
+    connection: Mutex<Connection>
+}
+
+impl MatchStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(connection: Connection) -> Result<Self> {
+        connection.pragma_update(None, "foreign_keys", true)?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(MatchStore { connection: Mutex::new(connection) })
+    }
+
+    pub fn save_match(&self, match_: &MatchDto) -> Result<()> {
+        let patch = match_.info.patch().ok();
+        let mut connection = self.connection.lock().unwrap();
+        let transaction = connection.transaction()?;
+
+        transaction.execute(
+            "INSERT INTO matches (match_id, queue_id, patch_major, patch_minor, game_creation, data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT (match_id) DO UPDATE SET
+                queue_id = excluded.queue_id,
+                patch_major = excluded.patch_major,
+                patch_minor = excluded.patch_minor,
+                game_creation = excluded.game_creation,
+                data = excluded.data",
+            params![
+                match_.metadata.match_id,
+                match_.info.queue_id.id(),
+                patch.map(|patch| patch.major),
+                patch.map(|patch| patch.minor),
+                match_.info.game_creation,
+                serde_json::to_string(match_)?
+            ]
+        )?;
+
+        transaction.execute("DELETE FROM match_participants WHERE match_id = ?1", [&match_.metadata.match_id])?;
+        for participant in &match_.info.participants {
+            transaction.execute(
+                "INSERT INTO match_participants (match_id, participant_id, puuid, champion_id, team_id, win)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    match_.metadata.match_id,
+                    participant.participant_id,
+                    participant.puuid,
+                    participant.champion_id.key(),
+                    participant.team_id,
+                    participant.win
+                ]
+            )?;
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+
+    pub fn get_match(&self, match_id: &str) -> Result<Option<MatchDto>> {
+        self.get("SELECT data FROM matches WHERE match_id = ?1", match_id)
+    }
+
+    pub fn contains_match(&self, match_id: &str) -> Result<bool> {
+        let connection = self.connection.lock().unwrap();
+        let found = connection
+            .query_row("SELECT 1 FROM matches WHERE match_id = ?1", [match_id], |_| Ok(()))
+            .optional()?;
+        Ok(found.is_some())
+    }
+
+    /// Matches for the query, most recent first.
+    pub fn matches(&self, query: &MatchQuery) -> Result<Vec<MatchDto>> {
+        let (sql, values) = query.to_sql("m.data");
+        self.query(&sql, values, |data| Ok(serde_json::from_str(&data)?))
+    }
+
+    /// Ids of the matches for the query, most recent first.
+    pub fn match_ids(&self, query: &MatchQuery) -> Result<Vec<String>> {
+        let (sql, values) = query.to_sql("m.match_id");
+        self.query(&sql, values, Ok)
+    }
+
+    pub fn save_timeline(&self, match_id: &str, timeline: &MatchTimelineDto) -> Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT INTO timelines (match_id, data) VALUES (?1, ?2)
+             ON CONFLICT (match_id) DO UPDATE SET data = excluded.data",
+            params![match_id, serde_json::to_string(timeline)?]
+        )?;
+        Ok(())
+    }
+
+    pub fn get_timeline(&self, match_id: &str) -> Result<Option<MatchTimelineDto>> {
+        self.get("SELECT data FROM timelines WHERE match_id = ?1", match_id)
+    }
+
+    pub fn save_summoner(&self, summoner: &SummonerDTO) -> Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT INTO summoners (puuid, summoner_id, account_id, data) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (puuid) DO UPDATE SET
+                summoner_id = excluded.summoner_id,
+                account_id = excluded.account_id,
+                data = excluded.data",
+            params![summoner.puuid, summoner.id, summoner.account_id, serde_json::to_string(summoner)?]
+        )?;
+        Ok(())
+    }
+
+    pub fn get_summoner_by_puuid(&self, puuid: &str) -> Result<Option<SummonerDTO>> {
+        self.get("SELECT data FROM summoners WHERE puuid = ?1", puuid)
+    }
+
+    pub fn save_account(&self, account: &AccountDto) -> Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT INTO accounts (puuid, game_name, tag_line, data) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (puuid) DO UPDATE SET
+                game_name = excluded.game_name,
+                tag_line = excluded.tag_line,
+                data = excluded.data",
+            params![account.puuid, account.game_name, account.tag_line, serde_json::to_string(account)?]
+        )?;
+        Ok(())
+    }
+
+    pub fn get_account_by_puuid(&self, puuid: &str) -> Result<Option<AccountDto>> {
+        self.get("SELECT data FROM accounts WHERE puuid = ?1", puuid)
+    }
+
+    fn get<T: DeserializeOwned>(&self, sql: &str, key: &str) -> Result<Option<T>> {
+        let connection = self.connection.lock().unwrap();
+        let data: Option<String> = connection.query_row(sql, [key], |row| row.get(0)).optional()?;
+        Ok(data.map(|data| serde_json::from_str(&data)).transpose()?)
+    }
+
+    fn query<T>(&self, sql: &str, values: Vec<Value>, map: impl Fn(String) -> Result<T>) -> Result<Vec<T>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare(sql)?;
+        let rows = statement.query_map(rusqlite::params_from_iter(values), |row| row.get::<_, String>(0))?;
+        rows.map(|row| map(row?)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn upsert_and_query_matches() {
+        let store = MatchStore::open_in_memory().expect("Failed to open store");
+        let match_ = fixtures::match_();
+        let puuid = match_.info.participants[2].puuid.clone();
+        let created = match_.info.game_creation;
+
+        store.save_match(&match_).expect("Failed to save match");
+        store.save_match(&match_).expect("Failed to save match");
+
+        let mut older = fixtures::match_();
+        older.metadata.match_id = "EUW1_6151255500".to_string();
+        older.info.game_creation -= 86_400_000;
+        older.info.queue_id = Queue::Aram;
+        store.save_match(&older).expect("Failed to save match");
+
+        assert!(store.contains_match(&match_.metadata.match_id).expect("Failed to query"));
+        assert_eq!(store.get_match(&match_.metadata.match_id).expect("Failed to query"), Some(match_.clone()));
+        assert_eq!(store.get_match("EUW1_0").expect("Failed to query"), None);
+
+        let ids = |query: MatchQuery| store.match_ids(&query).expect("Failed to query");
+        assert_eq!(ids(MatchQuery::new().with_puuid(&puuid)), [match_.metadata.match_id.clone(), older.metadata.match_id.clone()]);
+        assert_eq!(ids(MatchQuery::new().with_puuid(&puuid).with_queue(Queue::RankedSolo5x5).with_start_time(created - 1000)).len(), 1);
+        assert_eq!(ids(MatchQuery::new().with_puuid(&puuid).with_start_time(created + 1)).len(), 0);
+        assert_eq!(ids(MatchQuery::new().with_puuid(&puuid).with_champion(Champion::AHRI)).len(), 0);
+        assert_eq!(ids(MatchQuery::new().with_champion(Champion::AHRI)).len(), 2);
+        assert_eq!(ids(MatchQuery::new().with_patch(Patch::new(12, 21)).with_count(1)).len(), 1);
+        assert_eq!(ids(MatchQuery::new().with_patch(Patch::new(13, 1))).len(), 0);
+
+        let matches = store.matches(&MatchQuery::new().with_queue(Queue::Aram)).expect("Failed to query");
+        assert_eq!(matches, [older]);
+    }
+
+    #[test]
+    fn participants_sharing_a_puuid() {
+        let store = MatchStore::open_in_memory().expect("Failed to open store");
+        let mut match_ = fixtures::match_();
+        for participant in &mut match_.info.participants[..2] {
+            participant.puuid = "BOT".to_string();
+        }
+        store.save_match(&match_).expect("Failed to save match");
+
+        for participant in &match_.info.participants[..2] {
+            let query = MatchQuery::new().with_puuid("BOT").with_champion(participant.champion_id);
+            assert_eq!(store.match_ids(&query).expect("Failed to query"), [match_.metadata.match_id.clone()]);
+        }
+    }
+
+    #[test]
+    fn timelines_summoners_and_accounts() {
+        let store = MatchStore::open_in_memory().expect("Failed to open store");
+        let timeline = fixtures::timeline();
+        store.save_timeline("EUW1_6151255544", &timeline).expect("Failed to save timeline");
+        assert_eq!(store.get_timeline("EUW1_6151255544").expect("Failed to query"), Some(timeline));
+
+        let mut account = AccountDto { puuid: "abc".to_string(), game_name: Some("Påsan".to_string()), tag_line: None };
+        store.save_account(&account).expect("Failed to save account");
+        account.tag_line = Some("Neeko".to_string());
+        store.save_account(&account).expect("Failed to save account");
+        assert_eq!(store.get_account_by_puuid("abc").expect("Failed to query"), Some(account));
+
+        let summoner = SummonerDTO {
+            account_id: "account".to_string(),
+            profile_icon_id: 1,
+            revision_date: 0,
+            name: "Påsan".to_string(),
+            id: "summoner".to_string(),
+            puuid: "abc".to_string(),
+            summoner_level: 30
+        };
+        store.save_summoner(&summoner).expect("Failed to save summoner");
+        assert_eq!(store.get_summoner_by_puuid("abc").expect("Failed to query"), Some(summoner));
+        assert_eq!(store.get_summoner_by_puuid("def").expect("Failed to query"), None);
+    }
+}
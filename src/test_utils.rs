@@ -0,0 +1,163 @@
+use std::{collections::HashMap, net::SocketAddr, path::PathBuf, sync::{Arc, Mutex}};
+use tokio::{io::{AsyncReadExt, AsyncWriteExt}, net::TcpListener};
+
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: String
+}
+
+impl MockRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+}
+
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String
+}
+
+impl MockResponse {
+    pub fn json(status: u16, body: impl Into<String>) -> Self {
+        MockResponse {
+            status,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.into()
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+type Handler = dyn Fn(&MockRequest) -> MockResponse + Send + Sync;
+
+/// Minimal HTTP/1.1 server standing in for Riot's services in tests.
+pub struct MockServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<MockRequest>>>
+}
+
+impl MockServer {
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("Failed to bind mock server");
+        let addr = listener.local_addr().expect("Failed to get mock server address");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else { break };
+                let recorded = recorded.clone();
+                let handler = handler.clone();
+                tokio::spawn(async move {
+                    let Some(request) = read_request(&mut stream).await else { return };
+                    let response = handler(&request);
+                    recorded.lock().unwrap().push(request);
+
+                    let mut head = format!("HTTP/1.1 {} Mock\r\n", response.status);
+                    for (name, value) in &response.headers {
+                        head.push_str(&format!("{name}: {value}\r\n"));
+                    }
+                    head.push_str(&format!("Content-Length: {}\r\nConnection: close\r\n\r\n", response.body.len()));
+
+                    let _ = stream.write_all(head.as_bytes()).await;
+                    let _ = stream.write_all(response.body.as_bytes()).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+
+        MockServer { addr, requests }
+    }
+
+    /// Serves the files below `root` by request path, like a static data mirror would.
+    pub async fn serve_dir(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        MockServer::start(move |request| {
+            let path = request.path.split('?').next().unwrap_or_default().trim_start_matches('/');
+            match std::fs::read_to_string(root.join(path)) {
+                Ok(body) => MockResponse::json(200, body),
+                Err(_) => MockResponse::json(404, "Not Found")
+            }
+        }).await
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(stream: &mut tokio::net::TcpStream) -> Option<MockRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let body = String::from_utf8_lossy(&buf[header_end..]).to_string();
+
+    Some(MockRequest { method, path, headers, body })
+}
+
+/// The match EUW1_6151255544 and its timeline, shared by the tests.
+pub mod fixtures {
+    use crate::endpoints::constants::{MatchDto, MatchTimelineDto};
+
+    pub const MATCH_JSON: &str = include_str!("../tests/fixtures/match.json");
+    pub const TIMELINE_JSON: &str = include_str!("../tests/fixtures/timeline.json");
+
+    pub fn match_() -> MatchDto {
+        serde_json::from_str(MATCH_JSON).expect("Failed to deserialize")
+    }
+
+    pub fn timeline() -> MatchTimelineDto {
+        serde_json::from_str(TIMELINE_JSON).expect("Failed to deserialize")
+    }
+}
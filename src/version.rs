@@ -0,0 +1,197 @@
+use crate::endpoints::constants::MatchDto;
+use serde::{Deserialize, Serialize};
+use std::{fmt, ops::RangeBounds, str::FromStr};
+
+/// A major.minor patch such as 13.1, which is what static data is published for.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Patch {
+    pub major: u32,
+    pub minor: u32
+}
+
+impl Patch {
+    pub fn new(major: u32, minor: u32) -> Self {
+        Patch { major, minor }
+    }
+
+    /// The ranked season the patch belongs to. Splits are only known for 2023 to 2025, the
+    /// seasons before had none and the ones after are yet to be announced.
+    pub fn season(&self) -> Option<Season> {
+        if self.major < 3 {
+            return None;
+        }
+
+        let split = match (self.major, self.minor) {
+            (13, ..=13) | (14, ..=9) | (15, ..=8) => Some(1),
+            (13, _) | (14, 10..=18) | (15, 9..=16) => Some(2),
+            (14, _) | (15, _) => Some(3),
+            _ => None
+        };
+
+        Some(Season { year: 2010 + self.major, split })
+    }
+}
+
+/// A full game version such as "13.1.482.1234", as found in `InfoDto::game_version`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GameVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub build: u32,
+    pub revision: u32
+}
+
+impl GameVersion {
+    pub fn new(major: u32, minor: u32, build: u32, revision: u32) -> Self {
+        GameVersion { major, minor, build, revision }
+    }
+
+    pub fn patch(&self) -> Patch {
+        Patch::new(self.major, self.minor)
+    }
+
+    pub fn season(&self) -> Option<Season> {
+        self.patch().season()
+    }
+}
+
+/// Parses up to four dot-separated numbers, treating missing trailing parts as 0.
+impl FromStr for GameVersion {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseVersionError { version: s.to_string() };
+        let parts = s
+            .split('.')
+            .map(|part| part.parse().map_err(|_| err()))
+            .collect::<Result<Vec<u32>, _>>()?;
+
+        match parts[..] {
+            [major, minor] => Ok(GameVersion::new(major, minor, 0, 0)),
+            [major, minor, build] => Ok(GameVersion::new(major, minor, build, 0)),
+            [major, minor, build, revision] => Ok(GameVersion::new(major, minor, build, revision)),
+            _ => Err(err())
+        }
+    }
+}
+
+impl fmt::Display for GameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}.{}", self.major, self.minor, self.build, self.revision)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Season {
+    pub year: u32,
+    pub split: Option<u32>
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.split {
+            Some(split) => write!(f, "{} Split {split}", self.year),
+            None => write!(f, "{}", self.year)
+        }
+    }
+}
+
+/// The matches played on a patch within `patches`, e.g. `Patch::new(13, 5)..` for "13.5 and
+/// later". Matches with an unparsable game version are left out.
+pub fn filter_by_patch<'a, R>(
+    matches: impl IntoIterator<Item = &'a MatchDto>,
+    patches: R
+) -> impl Iterator<Item = &'a MatchDto>
+where
+    R: RangeBounds<Patch>
+{
+    matches
+        .into_iter()
+        .filter(move |match_| match_.info.patch().is_ok_and(|patch| patches.contains(&patch)))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVersionError {
+    pub version: String
+}
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid version: '{}'", self.version)
+    }
+}
+
+impl std::error::Error for ParseVersionError {}
+
+/// Parses "13.1" as well as longer versions such as the game version "13.1.482.1234" or the
+/// Data Dragon version "13.1.1", keeping only major and minor. Accepts the same input as
+/// [`GameVersion`].
+impl FromStr for Patch {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<GameVersion>().map(|version| version.patch())
+    }
+}
+
+impl fmt::Display for Patch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixtures;
+
+    #[test]
+    fn parse_patch() {
+        assert_eq!("13.1.482.1234".parse(), Ok(Patch::new(13, 1)));
+        assert_eq!("12.21.1".parse(), Ok(Patch::new(12, 21)));
+        assert_eq!(Patch::new(13, 10).to_string(), "13.10");
+        assert!(Patch::new(13, 10) > Patch::new(13, 9));
+        assert!("lolpatch_7.20".parse::<Patch>().is_err());
+        assert!("13.1.a".parse::<Patch>().is_err());
+        assert!("13.1.1.1.1".parse::<Patch>().is_err());
+    }
+
+    #[test]
+    fn parse_and_order_game_versions() {
+        let version: GameVersion = "13.1.482.1234".parse().expect("Failed to parse");
+        assert_eq!(version, GameVersion::new(13, 1, 482, 1234));
+        assert_eq!(version.patch(), Patch::new(13, 1));
+        assert_eq!(version.to_string(), "13.1.482.1234");
+
+        let mut versions: Vec<GameVersion> = ["13.10.1.1", "13.9.500.1", "13.9.499.2", "12.23.1.1"]
+            .iter()
+            .map(|version| version.parse().expect("Failed to parse"))
+            .collect();
+        versions.sort();
+        assert_eq!(versions.iter().map(GameVersion::to_string).collect::<Vec<_>>(), ["12.23.1.1", "13.9.499.2", "13.9.500.1", "13.10.1.1"]);
+
+        assert!("13".parse::<GameVersion>().is_err());
+        assert!("13.1.a.1".parse::<GameVersion>().is_err());
+    }
+
+    #[test]
+    fn seasons() {
+        assert_eq!(Patch::new(12, 21).season(), Some(Season { year: 2022, split: None }));
+        assert_eq!(Patch::new(13, 13).season().map(|season| season.to_string()), Some("2023 Split 1".to_string()));
+        assert_eq!(Patch::new(13, 14).season().and_then(|season| season.split), Some(2));
+        assert_eq!(Patch::new(14, 19).season().map(|season| season.to_string()), Some("2024 Split 3".to_string()));
+        assert_eq!(Patch::new(1, 0).season(), None);
+    }
+
+    #[test]
+    fn filter_matches_by_patch() {
+        let match_ = fixtures::match_();
+        let mut older = match_.clone();
+        older.info.game_version = "12.9.1.1".to_string();
+        let matches = [match_, older];
+
+        assert_eq!(filter_by_patch(&matches, Patch::new(12, 10)..).count(), 1);
+        assert_eq!(filter_by_patch(&matches, ..=Patch::new(12, 21)).count(), 2);
+        assert_eq!(filter_by_patch(&matches, Patch::new(12, 9)..Patch::new(12, 21)).count(), 1);
+    }
+}